@@ -0,0 +1,167 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// First 8 bytes of `sha256("zk-nft-transfer-hook-interface:execute")`
+pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [223, 6, 185, 229, 230, 205, 189, 247];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferHookInstruction {
+    /// Runs the hook logic for the transfer of `asset_id` from `sender` to
+    /// `recipient`.
+    Execute {
+        asset_id: [u8; 32],
+        sender: Pubkey,
+        recipient: Pubkey,
+    },
+}
+
+impl TransferHookInstruction {
+    pub fn pack(&self) -> Vec<u8> {
+        match self {
+            TransferHookInstruction::Execute {
+                asset_id,
+                sender,
+                recipient,
+            } => {
+                let mut data = Vec::with_capacity(8 + 32 * 3);
+                data.extend_from_slice(&EXECUTE_DISCRIMINATOR);
+                data.extend_from_slice(asset_id);
+                data.extend_from_slice(sender.as_ref());
+                data.extend_from_slice(recipient.as_ref());
+                data
+            }
+        }
+    }
+
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() != 8 + 32 * 3 || input[..8] != EXECUTE_DISCRIMINATOR {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let read_bytes =
+            |offset: usize| -> [u8; 32] { input[offset..offset + 32].try_into().unwrap() };
+        Ok(TransferHookInstruction::Execute {
+            asset_id: read_bytes(8),
+            sender: Pubkey::new_from_array(read_bytes(40)),
+            recipient: Pubkey::new_from_array(read_bytes(72)),
+        })
+    }
+}
+
+/// Builds the `Execute` instruction zk-nft sends to a transfer-hook program.
+pub fn execute(
+    program_id: &Pubkey,
+    group: &Pubkey,
+    authority: &Pubkey,
+    recipient: &Pubkey,
+    asset_id: [u8; 32],
+    sender: &Pubkey,
+    additional_accounts: &[AccountMeta],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*group, false),
+        AccountMeta::new_readonly(*authority, false),
+        AccountMeta::new_readonly(*recipient, false),
+    ];
+    accounts.extend_from_slice(additional_accounts);
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: TransferHookInstruction::Execute {
+            asset_id,
+            sender: *sender,
+            recipient: *recipient,
+        }
+        .pack(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::hash::hash;
+
+    use super::*;
+
+    #[test]
+    fn execute_discriminator_matches_its_preimage() {
+        assert_eq!(
+            hash(b"zk-nft-transfer-hook-interface:execute").to_bytes()[..8],
+            EXECUTE_DISCRIMINATOR
+        );
+    }
+
+    #[test]
+    fn pack_and_unpack_round_trip() {
+        let instruction = TransferHookInstruction::Execute {
+            asset_id: [7; 32],
+            sender: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+        };
+        assert_eq!(
+            TransferHookInstruction::unpack(&instruction.pack()).unwrap(),
+            instruction
+        );
+    }
+
+    #[test]
+    fn unpack_rejects_invalid_data() {
+        let mut data = TransferHookInstruction::Execute {
+            asset_id: [7; 32],
+            sender: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+        }
+        .pack();
+
+        assert_eq!(
+            TransferHookInstruction::unpack(&data[..data.len() - 1]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        data[0] ^= 1;
+        assert_eq!(
+            TransferHookInstruction::unpack(&data),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn execute_passes_the_additional_accounts_after_the_fixed_ones() {
+        let program_id = Pubkey::new_unique();
+        let group = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let sender = Pubkey::new_unique();
+        let additional_account = AccountMeta::new(Pubkey::new_unique(), false);
+
+        let instruction = execute(
+            &program_id,
+            &group,
+            &authority,
+            &recipient,
+            [7; 32],
+            &sender,
+            std::slice::from_ref(&additional_account),
+        );
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(group, false),
+                AccountMeta::new_readonly(authority, false),
+                AccountMeta::new_readonly(recipient, false),
+                additional_account,
+            ]
+        );
+        assert_eq!(
+            TransferHookInstruction::unpack(&instruction.data).unwrap(),
+            TransferHookInstruction::Execute {
+                asset_id: [7; 32],
+                sender,
+                recipient,
+            }
+        );
+    }
+}
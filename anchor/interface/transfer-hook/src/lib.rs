@@ -0,0 +1,15 @@
+//! Interface a transfer-hook program implements to be invoked by zk-nft.
+//!
+//! When a group registers a transfer-hook program, every `transfer` of one of
+//! its assets CPIs into that program with an [`instruction::TransferHookInstruction::Execute`]
+//! instruction. If the hook returns an error, the transfer fails.
+//!
+//! The hook receives, in order:
+//! 0. `[]` the group account
+//! 1. `[]` the authority that requested the transfer
+//! 2. `[]` the recipient
+//! 3. ..  every account following the hook program in the transfer's remaining accounts
+
+pub mod instruction;
+
+pub use instruction::*;
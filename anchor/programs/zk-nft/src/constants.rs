@@ -1,4 +1,25 @@
 pub const CPI_AUTHORITY_SEED: &str = "cpi_authority";
 
+pub const DECOMPRESSED_MINT_SEED: &[u8] = b"decompressed_mint";
+
 pub const UPLOADED_BLOB_BUFFER_START: usize = 8 + // discriminator
-    32; // authority
+    32 + // authority
+    1 + // content encoding
+    32 * MAX_BLOB_UPLOADERS + // uploaders
+    1 + // finalized
+    32; // hash
+
+pub const MAX_BLOB_UPLOADERS: usize = 4;
+
+pub const MAX_CONTENT_TYPE_LEN: usize = 64;
+
+pub const MAX_GROUP_CONTENT_TYPES: usize = 8;
+
+pub const MAX_CREATORS: usize = 5;
+
+pub const MAX_MINT_PHASES: usize = 4;
+
+pub const MAX_GROUP_DEPTH: usize = 4;
+
+/// Slots between closing a reveal's minting and the slot whose hash draws it.
+pub const REVEAL_SLOT_DELAY: u64 = 16;
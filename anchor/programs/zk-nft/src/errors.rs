@@ -10,4 +10,128 @@ pub enum ZkNftError {
     AssetIsLocked,
     #[msg("Authority is not the owner or delegate")]
     InvalidAuthority,
+    #[msg("SharedBlobInputMissing")]
+    SharedBlobInputMissing,
+    #[msg("SharedBlobHashMissing")]
+    SharedBlobHashMissing,
+    #[msg("InvalidContentType")]
+    InvalidContentType,
+    #[msg("ContentTypeNotAllowed")]
+    ContentTypeNotAllowed,
+    #[msg("TooManyContentTypes")]
+    TooManyContentTypes,
+    #[msg("InvalidBlobChunks")]
+    InvalidBlobChunks,
+    #[msg("TooManyUploaders")]
+    TooManyUploaders,
+    #[msg("Uploaded blob authority did not sign")]
+    InvalidUploadedBlobAuthority,
+    #[msg("UploadedBlobFinalized")]
+    UploadedBlobFinalized,
+    #[msg("UploadedBlobNotFinalized")]
+    UploadedBlobNotFinalized,
+    #[msg("UploadedBlobMismatch")]
+    UploadedBlobMismatch,
+    #[msg("Group authority must sign when a group is provided")]
+    GroupAuthorityMissing,
+    #[msg("GroupMismatch")]
+    GroupMismatch,
+    #[msg("InvalidRoyalties")]
+    InvalidRoyalties,
+    #[msg("Creator account missing from remaining accounts")]
+    CreatorAccountMissing,
+    #[msg("AssetNonTransferable")]
+    AssetNonTransferable,
+    #[msg("AssetAccountMissing")]
+    AssetAccountMissing,
+    #[msg("GroupAccountMissing")]
+    GroupAccountMissing,
+    #[msg("AssetNotLocked")]
+    AssetNotLocked,
+    #[msg("GroupFrozen")]
+    GroupFrozen,
+    #[msg("Transfer hook program missing from remaining accounts")]
+    TransferHookProgramMissing,
+    #[msg("RecipientPolicyMissing")]
+    RecipientPolicyMissing,
+    #[msg("InvalidRecipientProof")]
+    InvalidRecipientProof,
+    #[msg("RecipientNotAllowed")]
+    RecipientNotAllowed,
+    #[msg("InvalidDelegateExpiry")]
+    InvalidDelegateExpiry,
+    #[msg("PaymentAccountMissing")]
+    PaymentAccountMissing,
+    #[msg("InvalidPaymentAccount")]
+    InvalidPaymentAccount,
+    #[msg("SwapSameOwner")]
+    SwapSameOwner,
+    #[msg("SwapSameAsset")]
+    SwapSameAsset,
+    #[msg("InvalidPrice")]
+    InvalidPrice,
+    #[msg("AssetNotListed")]
+    AssetNotListed,
+    #[msg("BidMismatch")]
+    BidMismatch,
+    #[msg("AssetDecompressed")]
+    AssetDecompressed,
+    #[msg("AssetNotDecompressed")]
+    AssetNotDecompressed,
+    #[msg("AttributesMissing")]
+    AttributesMissing,
+    #[msg("Assets with enforced royalties or group transfer rules cannot be decompressed")]
+    DecompressionRestricted,
+    #[msg("UnsupportedTokenStandard")]
+    UnsupportedTokenStandard,
+    #[msg("InvalidMetadata")]
+    InvalidMetadata,
+    #[msg("AssetHasEdition")]
+    AssetHasEdition,
+    #[msg("NotMasterEdition")]
+    NotMasterEdition,
+    #[msg("EditionSupplyExceeded")]
+    EditionSupplyExceeded,
+    #[msg("MasterEditionPrinted")]
+    MasterEditionPrinted,
+    #[msg("MintNotOpen")]
+    MintNotOpen,
+    #[msg("MintSoldOut")]
+    MintSoldOut,
+    #[msg("WalletLimitReached")]
+    WalletLimitReached,
+    #[msg("MintRecordMismatch")]
+    MintRecordMismatch,
+    #[msg("InvalidAddressMerkleTree")]
+    InvalidAddressMerkleTree,
+    #[msg("MintNotClosed")]
+    MintNotClosed,
+    #[msg("TooManyMintPhases")]
+    TooManyMintPhases,
+    #[msg("InvalidMintPhase")]
+    InvalidMintPhase,
+    #[msg("NotAllowlisted")]
+    NotAllowlisted,
+    #[msg("RevealLocked")]
+    RevealLocked,
+    #[msg("PlaceholderMissing")]
+    PlaceholderMissing,
+    #[msg("AssetNotRevealable")]
+    AssetNotRevealable,
+    #[msg("InvalidRevealProof")]
+    InvalidRevealProof,
+    #[msg("RevealRequiresMaxSupply")]
+    RevealRequiresMaxSupply,
+    #[msg("RevealNotStarted")]
+    RevealNotStarted,
+    #[msg("RevealAlreadyStarted")]
+    RevealAlreadyStarted,
+    #[msg("RevealSlotNotReached")]
+    RevealSlotNotReached,
+    #[msg("RevealIncomplete")]
+    RevealIncomplete,
+    #[msg("Parent group authority must sign when a parent is provided")]
+    ParentAuthorityMissing,
+    #[msg("GroupTooDeep")]
+    GroupTooDeep,
 }
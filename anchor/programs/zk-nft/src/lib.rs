@@ -9,14 +9,137 @@ use light_system_program::invoke::processor::CompressedProof;
 use processor::*;
 use state::*;
 
+#[cfg(feature = "decode")]
+pub use state::{BlobChunkHeader, ContentEncoding};
+#[cfg(feature = "decode")]
+pub use utils::{decode_blob, reassemble_blob};
+
 declare_id!("zkNFTi24GW95YYfM8jNM2tDDPmDnDm7EQuze8jJ66sn");
 
 #[program]
 pub mod zk_nft {
     use super::*;
 
-    pub fn create_group<'info>(ctx: Context<CreateGroup<'info>>, max_size: u64) -> Result<()> {
-        processor::create_group(ctx, max_size)
+    pub fn create_group<'info>(
+        ctx: Context<CreateGroup<'info>>,
+        max_size: u64,
+        non_transferable: bool,
+    ) -> Result<()> {
+        processor::create_group(ctx, max_size, non_transferable)
+    }
+
+    pub fn set_group_content_types<'info>(
+        ctx: Context<SetGroupContentTypes<'info>>,
+        content_types: Vec<String>,
+    ) -> Result<()> {
+        processor::set_group_content_types(ctx, content_types)
+    }
+
+    pub fn set_group_royalties<'info>(
+        ctx: Context<SetGroupRoyalties<'info>>,
+        royalties: Option<Royalties>,
+    ) -> Result<()> {
+        processor::set_group_royalties(ctx, royalties)
+    }
+
+    pub fn set_group_permanent_delegate<'info>(
+        ctx: Context<SetGroupPermanentDelegate<'info>>,
+        permanent_delegate: Option<Pubkey>,
+    ) -> Result<()> {
+        processor::set_group_permanent_delegate(ctx, permanent_delegate)
+    }
+
+    pub fn set_group_collection<'info>(
+        ctx: Context<SetGroupCollection<'info>>,
+        collection: Option<Pubkey>,
+    ) -> Result<()> {
+        processor::set_group_collection(ctx, collection)
+    }
+
+    pub fn set_group_frozen<'info>(
+        ctx: Context<SetGroupFrozen<'info>>,
+        frozen: bool,
+    ) -> Result<()> {
+        processor::set_group_frozen(ctx, frozen)
+    }
+
+    pub fn set_group_transfer_hook<'info>(
+        ctx: Context<SetGroupTransferHook<'info>>,
+        transfer_hook_program: Option<Pubkey>,
+    ) -> Result<()> {
+        processor::set_group_transfer_hook(ctx, transfer_hook_program)
+    }
+
+    pub fn create_recipient_policy<'info>(
+        ctx: Context<CreateRecipientPolicy<'info>>,
+        mode: RecipientPolicyMode,
+        root: [u8; 32],
+    ) -> Result<()> {
+        processor::create_recipient_policy(ctx, mode, root)
+    }
+
+    pub fn update_recipient_policy<'info>(
+        ctx: Context<UpdateRecipientPolicy<'info>>,
+        mode: RecipientPolicyMode,
+        root: [u8; 32],
+    ) -> Result<()> {
+        processor::update_recipient_policy(ctx, mode, root)
+    }
+
+    pub fn close_recipient_policy<'info>(ctx: Context<CloseRecipientPolicy<'info>>) -> Result<()> {
+        processor::close_recipient_policy(ctx)
+    }
+
+    pub fn create_mint_config<'info>(
+        ctx: Context<CreateMintConfig<'info>>,
+        params: MintConfigParams,
+    ) -> Result<()> {
+        processor::create_mint_config(ctx, params)
+    }
+
+    pub fn update_mint_config<'info>(
+        ctx: Context<UpdateMintConfig<'info>>,
+        params: MintConfigParams,
+    ) -> Result<()> {
+        processor::update_mint_config(ctx, params)
+    }
+
+    pub fn close_mint_config<'info>(ctx: Context<CloseMintConfig<'info>>) -> Result<()> {
+        processor::close_mint_config(ctx)
+    }
+
+    pub fn mint_from_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintFromConfig<'info>>,
+        proof: CompressedProof,
+        new_address_params_packed: NewAddressParams,
+        phase: Option<u8>,
+        allowlist_proof: Option<AllowlistProof>,
+        mint_record_params: Option<MintRecordParams>,
+        placeholder_proof: Option<CompressedProof>,
+    ) -> Result<()> {
+        processor::mint_from_config(
+            ctx,
+            proof,
+            new_address_params_packed,
+            phase,
+            allowlist_proof,
+            mint_record_params,
+            placeholder_proof,
+        )
+    }
+
+    pub fn start_reveal<'info>(ctx: Context<StartReveal<'info>>) -> Result<()> {
+        processor::start_reveal(ctx)
+    }
+
+    pub fn reveal<'info>(
+        ctx: Context<'_, '_, '_, 'info, Reveal<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        asset_data_params: AssetDataParams,
+        reveal_params: RevealParams,
+    ) -> Result<()> {
+        processor::reveal(ctx, proof, asset_id, asset_data_params, reveal_params)
     }
 
     pub fn create_asset<'info>(
@@ -26,6 +149,8 @@ pub mod zk_nft {
         new_address_params_packed: NewAddressParams,
         blob_params: Option<BlobParams>,
         attributes_params: Option<AttributesParams>,
+        royalties: Option<Royalties>,
+        non_transferable: bool,
     ) -> Result<()> {
         processor::create_asset(
             ctx,
@@ -34,6 +159,8 @@ pub mod zk_nft {
             new_address_params_packed,
             blob_params,
             attributes_params,
+            royalties,
+            non_transferable,
         )
     }
 
@@ -43,19 +170,351 @@ pub mod zk_nft {
         asset_id: [u8; 32],
         base_data: BaseData,
         base_data_input: PackedInputCompressedPda,
+        asset_data_params: AssetDataParams,
+        sale_price: Option<u64>,
+        recipient_proof: Option<RecipientProof>,
+    ) -> Result<()> {
+        processor::transfer(
+            ctx,
+            proof,
+            asset_id,
+            base_data,
+            base_data_input,
+            asset_data_params,
+            sale_price,
+            recipient_proof,
+        )
+    }
+
+    pub fn set_membership_verified<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetMembershipVerified<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        asset_data_params: AssetDataParams,
+        verified: bool,
+    ) -> Result<()> {
+        processor::set_membership_verified(ctx, proof, asset_id, asset_data_params, verified)
+    }
+
+    pub fn create_shared_blob<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateSharedBlob<'info>>,
+        proof: CompressedProof,
+        shared_blob: SharedBlob,
+        new_address_params_packed: NewAddressParams,
+    ) -> Result<()> {
+        processor::create_shared_blob(ctx, proof, shared_blob, new_address_params_packed)
+    }
+
+    pub fn burn<'info>(
+        ctx: Context<'_, '_, '_, 'info, Burn<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+        asset_data_params: AssetDataParams,
+        blob_params: Option<AssetBlobParams>,
+        attributes_params: Option<AssetAttributesParams>,
+    ) -> Result<()> {
+        processor::burn(
+            ctx,
+            proof,
+            asset_id,
+            base_data,
+            base_data_input,
+            asset_data_params,
+            blob_params,
+            attributes_params,
+        )
+    }
+
+    pub fn approve<'info>(
+        ctx: Context<'_, '_, '_, 'info, Approve<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+        delegate_role: DelegateRole,
+        delegate_expiry: Option<i64>,
+    ) -> Result<()> {
+        processor::approve(
+            ctx,
+            proof,
+            asset_id,
+            base_data,
+            base_data_input,
+            delegate_role,
+            delegate_expiry,
+        )
+    }
+
+    pub fn revoke<'info>(
+        ctx: Context<'_, '_, '_, 'info, Revoke<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+    ) -> Result<()> {
+        processor::revoke(ctx, proof, asset_id, base_data, base_data_input)
+    }
+
+    pub fn rent_asset<'info>(
+        ctx: Context<'_, '_, '_, 'info, RentAsset<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+        expiry: i64,
+    ) -> Result<()> {
+        processor::rent_asset(ctx, proof, asset_id, base_data, base_data_input, expiry)
+    }
+
+    pub fn lock<'info>(
+        ctx: Context<'_, '_, '_, 'info, Lock<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+        asset_data_params: Option<AssetDataParams>,
+    ) -> Result<()> {
+        processor::lock(
+            ctx,
+            proof,
+            asset_id,
+            base_data,
+            base_data_input,
+            asset_data_params,
+        )
+    }
+
+    pub fn unlock<'info>(
+        ctx: Context<'_, '_, '_, 'info, Unlock<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+        asset_data_params: Option<AssetDataParams>,
+    ) -> Result<()> {
+        processor::unlock(
+            ctx,
+            proof,
+            asset_id,
+            base_data,
+            base_data_input,
+            asset_data_params,
+        )
+    }
+
+    pub fn swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        proof: CompressedProof,
+        leg_a: SwapLeg,
+        leg_b: SwapLeg,
+        payment: Option<SwapPayment>,
+    ) -> Result<()> {
+        processor::swap(ctx, proof, leg_a, leg_b, payment)
+    }
+
+    pub fn list<'info>(
+        ctx: Context<'_, '_, '_, 'info, List<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+        price: u64,
+        payment_mint: Option<Pubkey>,
+    ) -> Result<()> {
+        processor::list(
+            ctx,
+            proof,
+            asset_id,
+            base_data,
+            base_data_input,
+            price,
+            payment_mint,
+        )
+    }
+
+    pub fn delist<'info>(
+        ctx: Context<'_, '_, '_, 'info, Delist<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+    ) -> Result<()> {
+        processor::delist(ctx, proof, asset_id, base_data, base_data_input)
+    }
+
+    pub fn buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+        asset_data_params: AssetDataParams,
+        recipient_proof: Option<RecipientProof>,
+    ) -> Result<()> {
+        processor::buy(
+            ctx,
+            proof,
+            asset_id,
+            base_data,
+            base_data_input,
+            asset_data_params,
+            recipient_proof,
+        )
+    }
+
+    pub fn place_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceBid<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        bid_id: u64,
+        amount: u64,
+        new_address_params_packed: NewAddressParams,
+    ) -> Result<()> {
+        processor::place_bid(
+            ctx,
+            proof,
+            asset_id,
+            bid_id,
+            amount,
+            new_address_params_packed,
+        )
+    }
+
+    pub fn cancel_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelBid<'info>>,
+        proof: CompressedProof,
+        bid_params: BidParams,
+    ) -> Result<()> {
+        processor::cancel_bid(ctx, proof, bid_params)
+    }
+
+    pub fn accept_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptBid<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+        asset_data_params: AssetDataParams,
+        bid_params: BidParams,
+        recipient_proof: Option<RecipientProof>,
+    ) -> Result<()> {
+        processor::accept_bid(
+            ctx,
+            proof,
+            asset_id,
+            base_data,
+            base_data_input,
+            asset_data_params,
+            bid_params,
+            recipient_proof,
+        )
+    }
+
+    pub fn decompress<'info>(
+        ctx: Context<'_, '_, '_, 'info, Decompress<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+        asset_data_params: AssetDataParams,
+        attributes_params: Option<AssetAttributesParams>,
+    ) -> Result<()> {
+        processor::decompress(
+            ctx,
+            proof,
+            asset_id,
+            base_data,
+            base_data_input,
+            asset_data_params,
+            attributes_params,
+        )
+    }
+
+    pub fn compress<'info>(
+        ctx: Context<'_, '_, '_, 'info, Compress<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+    ) -> Result<()> {
+        processor::compress(ctx, proof, asset_id, base_data, base_data_input)
+    }
+
+    pub fn compress_from_spl<'info>(
+        ctx: Context<'_, '_, '_, 'info, CompressFromSpl<'info>>,
+        proof: CompressedProof,
+        new_address_params_packed: NewAddressParams,
+        attributes_proof: CompressedProof,
+    ) -> Result<()> {
+        processor::compress_from_spl(ctx, proof, new_address_params_packed, attributes_proof)
+    }
+
+    pub fn create_master_edition<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateMasterEdition<'info>>,
+        proof: CompressedProof,
+        asset_id: [u8; 32],
+        base_data: BaseData,
+        base_data_input: PackedInputCompressedPda,
+        asset_data_params: AssetDataParams,
+        max_supply: Option<u64>,
+    ) -> Result<()> {
+        processor::create_master_edition(
+            ctx,
+            proof,
+            asset_id,
+            base_data,
+            base_data_input,
+            asset_data_params,
+            max_supply,
+        )
+    }
+
+    pub fn print_edition<'info>(
+        ctx: Context<'_, '_, '_, 'info, PrintEdition<'info>>,
+        proof: CompressedProof,
+        master_id: [u8; 32],
+        master_base_data: BaseData,
+        master_base_data_input: PackedInputCompressedPda,
+        master_asset_data_params: AssetDataParams,
+        new_address_params_packed: NewAddressParams,
     ) -> Result<()> {
-        processor::transfer(ctx, proof, asset_id, base_data, base_data_input)
+        processor::print_edition(
+            ctx,
+            proof,
+            master_id,
+            master_base_data,
+            master_base_data_input,
+            master_asset_data_params,
+            new_address_params_packed,
+        )
     }
 
     pub fn upload_blob(ctx: Context<UploadBlob>, index: u32, bytes: Vec<u8>) -> Result<()> {
         processor::upload_blob(ctx, index, bytes)
     }
 
-    pub fn init_blob_upload(ctx: Context<InitBlobUpload>, _total_bytes: u32) -> Result<()> {
-        processor::init_blob_upload(ctx, _total_bytes)
+    pub fn init_blob_upload(
+        ctx: Context<InitBlobUpload>,
+        _total_bytes: u32,
+        content_encoding: ContentEncoding,
+    ) -> Result<()> {
+        processor::init_blob_upload(ctx, _total_bytes, content_encoding)
     }
 
     pub fn log_blob(ctx: Context<LogBlob>) -> Result<()> {
         processor::log_blob(ctx)
     }
+
+    pub fn set_blob_uploaders(
+        ctx: Context<SetBlobUploaders>,
+        uploaders: Vec<Pubkey>,
+    ) -> Result<()> {
+        processor::set_blob_uploaders(ctx, uploaders)
+    }
+
+    pub fn set_blob_authority(ctx: Context<SetBlobAuthority>, new_authority: Pubkey) -> Result<()> {
+        processor::set_blob_authority(ctx, new_authority)
+    }
 }
@@ -0,0 +1,165 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{
+    AssetDataParams, BaseData, BidParams, DelegateRole, Group, OwnerUpdatedEvent,
+    PackedInputCompressedPda, RecipientPolicy, RecipientProof, State,
+};
+use crate::utils::{
+    check_group_transfer_restrictions, get_bid_compressed_pda, get_old_and_new_compressed_pda,
+    get_unchanged_asset_data_compressed_pda, invoke_transfer_hook, pay_royalties,
+};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+/// Sells the asset to the bidder. The escrowed lamports are released to the
+/// owner, who pays the royalties out of them.
+pub fn accept_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptBid<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    base_data: BaseData,
+    base_data_input: PackedInputCompressedPda,
+    asset_data_params: AssetDataParams,
+    bid_params: BidParams,
+    recipient_proof: Option<RecipientProof>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let asset_data = &asset_data_params.data;
+    let bid = &bid_params.data;
+    require!(bid.asset_id == asset_id, ZkNftError::BidMismatch);
+    require_keys_eq!(
+        ctx.accounts.bidder.key(),
+        bid.bidder,
+        ZkNftError::BidMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        base_data.owner,
+        ZkNftError::InvalidAuthority
+    );
+    require!(
+        base_data.effective_state(now) == State::Unlocked,
+        ZkNftError::AssetIsLocked
+    );
+    require!(
+        !asset_data.non_transferable,
+        ZkNftError::AssetNonTransferable
+    );
+
+    let transfer_hook_program = check_group_transfer_restrictions(
+        asset_data,
+        ctx.accounts.group.as_deref(),
+        ctx.accounts.recipient_policy.as_deref(),
+        &bid.bidder,
+        recipient_proof.as_ref(),
+    )?;
+    if let Some(transfer_hook_program) = transfer_hook_program {
+        invoke_transfer_hook(
+            transfer_hook_program,
+            ctx.accounts.group.as_ref().unwrap().to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.bidder.to_account_info(),
+            ctx.remaining_accounts,
+            asset_id,
+            base_data.owner,
+        )?;
+    }
+
+    emit_cpi!(OwnerUpdatedEvent {
+        asset_id: asset_id.into(),
+        owner: bid.bidder,
+    });
+
+    let (old_state, new_state) = get_old_and_new_compressed_pda(
+        asset_id,
+        &base_data_input,
+        &base_data,
+        &BaseData {
+            owner: bid.bidder,
+            state: State::Unlocked,
+            delegate: None,
+            delegate_role: DelegateRole::All,
+            delegate_expiry: None,
+            price: None,
+            payment_mint: None,
+        },
+    )?;
+
+    // consumed unchanged, proving the bid pays the asset's own royalties
+    let (old_asset_data, new_asset_data) = get_unchanged_asset_data_compressed_pda(
+        ctx.remaining_accounts,
+        &asset_id,
+        &asset_data_params,
+    )?;
+
+    let old_bid = get_bid_compressed_pda(ctx.remaining_accounts, &bid_params)?;
+
+    // royalties are paid once the bid has been released to the owner
+    let seller = ctx.accounts.authority.to_account_info();
+    let remaining_accounts = ctx.remaining_accounts;
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: vec![old_state, old_asset_data, old_bid],
+        output_compressed_accounts: vec![new_state, new_asset_data],
+        compress_or_decompress_lamports: Some(bid.amount),
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    if let Some(royalties) = &asset_data.royalties {
+        pay_royalties(&seller, remaining_accounts, royalties, bid.amount)?;
+    }
+
+    Ok(())
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+pub struct AcceptBid<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Checked against the bid.
+    pub bidder: UncheckedAccount<'info>,
+    /// Required when the asset belongs to a group.
+    pub group: Option<Box<Account<'info, Group>>>,
+    /// Required when the group has a recipient policy.
+    pub recipient_policy: Option<Box<Account<'info, RecipientPolicy>>>,
+    /// CHECK: Checked by the light system program.
+    #[account(mut)]
+    pub sol_pool_pda: UncheckedAccount<'info>,
+    /// CHECK: Checked against the asset owner.
+    #[account(mut, address = authority.key())]
+    pub decompression_recipient: UncheckedAccount<'info>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
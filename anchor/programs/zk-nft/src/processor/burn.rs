@@ -0,0 +1,166 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{
+    AssetAttributesParams, AssetBlobParams, AssetBurnedEvent, AssetDataParams, BaseData, Edition,
+    Group, PackedInputCompressedPda, PermanentDelegateAction, PermanentDelegateEvent, State,
+};
+use crate::utils::{derive_asset_resource_address, get_old_compressed_pda};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+pub fn burn<'info>(
+    ctx: Context<'_, '_, '_, 'info, Burn<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    base_data: BaseData,
+    base_data_input: PackedInputCompressedPda,
+    asset_data_params: AssetDataParams,
+    blob_params: Option<AssetBlobParams>,
+    attributes_params: Option<AssetAttributesParams>,
+) -> Result<()> {
+    // a decompressed asset has to be compressed before it can be burned
+    require!(
+        base_data.state != State::Decompressed,
+        ZkNftError::AssetDecompressed
+    );
+    // every compressed account of the asset is burned with it, prints refer to
+    // their master's blob and attributes and have none of their own
+    let asset_data = &asset_data_params.data;
+    let is_print = matches!(asset_data.edition, Some(Edition::Print { .. }));
+    require!(
+        (asset_data.has_blob && !is_print) == blob_params.is_some()
+            && (asset_data.has_attributes && !is_print) == attributes_params.is_some(),
+        ZkNftError::AssetAccountMissing
+    );
+    // a printed master keeps the blob and attributes its prints refer to
+    require!(
+        !matches!(asset_data.edition, Some(Edition::Master { supply, .. }) if supply > 0),
+        ZkNftError::MasterEditionPrinted
+    );
+
+    let authority = ctx.accounts.authority.key();
+    if asset_data.permanent_delegate == Some(authority) {
+        emit_cpi!(PermanentDelegateEvent {
+            asset_id: asset_id.into(),
+            permanent_delegate: authority,
+            action: PermanentDelegateAction::Burn,
+        });
+    } else {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            base_data.effective_state(now) == State::Unlocked,
+            ZkNftError::AssetIsLocked
+        );
+        if authority != base_data.owner {
+            require!(
+                Some(authority) == base_data.active_delegate(now)
+                    && base_data.delegate_role.can_burn(),
+                ZkNftError::InvalidAuthority
+            );
+        }
+    }
+
+    // burned assets no longer count towards the size of their group
+    if let Some(asset_group) = asset_data.group {
+        let group = ctx
+            .accounts
+            .group
+            .as_mut()
+            .ok_or(ZkNftError::GroupAccountMissing)?;
+        require_keys_eq!(group.key(), asset_group, ZkNftError::GroupMismatch);
+        group.size = group.size.saturating_sub(1);
+    }
+
+    emit_cpi!(AssetBurnedEvent {
+        asset_id: asset_id.into(),
+    });
+
+    let mut input_compressed_accounts_with_merkle_context = vec![
+        get_old_compressed_pda(asset_id, &base_data_input, &base_data)?,
+        get_old_compressed_pda(
+            derive_asset_resource_address(
+                ctx.remaining_accounts,
+                asset_data_params.address_merkle_tree_account_index,
+                b"asset_data",
+                &asset_id,
+            )?,
+            &asset_data_params.input,
+            asset_data,
+        )?,
+    ];
+    if let Some(blob_params) = &blob_params {
+        input_compressed_accounts_with_merkle_context.push(get_old_compressed_pda(
+            derive_asset_resource_address(
+                ctx.remaining_accounts,
+                blob_params.address_merkle_tree_account_index,
+                b"blob",
+                &asset_id,
+            )?,
+            &blob_params.input,
+            &blob_params.data,
+        )?);
+    }
+    if let Some(attributes_params) = &attributes_params {
+        input_compressed_accounts_with_merkle_context.push(get_old_compressed_pda(
+            derive_asset_resource_address(
+                ctx.remaining_accounts,
+                attributes_params.address_merkle_tree_account_index,
+                b"attributes",
+                &asset_id,
+            )?,
+            &attributes_params.input,
+            &attributes_params.data,
+        )?);
+    }
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context,
+        output_compressed_accounts: Vec::new(),
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+pub struct Burn<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// Required when the asset belongs to a group.
+    #[account(mut)]
+    pub group: Option<Box<Account<'info, Group>>>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
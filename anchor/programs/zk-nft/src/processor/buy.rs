@@ -0,0 +1,234 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{
+    AssetDataParams, BaseData, DelegateRole, Group, OwnerUpdatedEvent, PackedInputCompressedPda,
+    RecipientPolicy, RecipientProof, Royalties, State,
+};
+use crate::utils::{
+    check_group_transfer_restrictions, get_old_and_new_compressed_pda,
+    get_unchanged_asset_data_compressed_pda, invoke_transfer_hook, pay_royalties,
+    pay_royalties_in_tokens, transfer_lamports, transfer_tokens,
+};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+/// Buys a listed asset at its listing price, in lamports or in the listing's
+/// payment mint tokens. Royalties are paid out of the price and the rest goes
+/// to the seller.
+pub fn buy<'info>(
+    ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    base_data: BaseData,
+    base_data_input: PackedInputCompressedPda,
+    asset_data_params: AssetDataParams,
+    recipient_proof: Option<RecipientProof>,
+) -> Result<()> {
+    let asset_data = &asset_data_params.data;
+    let now = Clock::get()?.unix_timestamp;
+    let price = base_data.price.ok_or(ZkNftError::AssetNotListed)?;
+    require!(
+        base_data.effective_state(now) == State::LockedByDelegate
+            && base_data.delegate == Some(ctx.accounts.cpi_authority_pda.key()),
+        ZkNftError::AssetNotListed
+    );
+    require_keys_eq!(
+        ctx.accounts.seller.key(),
+        base_data.owner,
+        ZkNftError::InvalidAuthority
+    );
+    require!(
+        !asset_data.non_transferable,
+        ZkNftError::AssetNonTransferable
+    );
+
+    let transfer_hook_program = check_group_transfer_restrictions(
+        asset_data,
+        ctx.accounts.group.as_deref(),
+        ctx.accounts.recipient_policy.as_deref(),
+        ctx.accounts.buyer.key,
+        recipient_proof.as_ref(),
+    )?;
+
+    pay_seller(
+        &ctx,
+        base_data.payment_mint,
+        asset_data.royalties.as_ref(),
+        price,
+    )?;
+
+    if let Some(transfer_hook_program) = transfer_hook_program {
+        let buyer = ctx.accounts.buyer.to_account_info();
+        invoke_transfer_hook(
+            transfer_hook_program,
+            ctx.accounts.group.as_ref().unwrap().to_account_info(),
+            buyer.clone(),
+            buyer,
+            ctx.remaining_accounts,
+            asset_id,
+            base_data.owner,
+        )?;
+    }
+
+    emit_cpi!(OwnerUpdatedEvent {
+        asset_id: asset_id.into(),
+        owner: ctx.accounts.buyer.key(),
+    });
+
+    let (old_state, new_state) = get_old_and_new_compressed_pda(
+        asset_id,
+        &base_data_input,
+        &base_data,
+        &BaseData {
+            owner: ctx.accounts.buyer.key(),
+            state: State::Unlocked,
+            delegate: None,
+            delegate_role: DelegateRole::All,
+            delegate_expiry: None,
+            price: None,
+            payment_mint: None,
+        },
+    )?;
+
+    // consumed unchanged, proving the sale paid the asset's own royalties
+    let (old_asset_data, new_asset_data) = get_unchanged_asset_data_compressed_pda(
+        ctx.remaining_accounts,
+        &asset_id,
+        &asset_data_params,
+    )?;
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: vec![old_state, old_asset_data],
+        output_compressed_accounts: vec![new_state, new_asset_data],
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+fn pay_seller<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Buy<'info>>,
+    payment_mint: Option<Pubkey>,
+    royalties: Option<&Royalties>,
+    price: u64,
+) -> Result<()> {
+    let buyer = ctx.accounts.buyer.to_account_info();
+    let Some(payment_mint) = payment_mint else {
+        let royalties_paid = match royalties {
+            Some(royalties) => pay_royalties(&buyer, ctx.remaining_accounts, royalties, price)?,
+            None => 0,
+        };
+        return transfer_lamports(
+            &buyer,
+            &ctx.accounts.seller.to_account_info(),
+            price.checked_sub(royalties_paid).unwrap(),
+        );
+    };
+
+    let mint = ctx
+        .accounts
+        .payment_mint
+        .as_ref()
+        .ok_or(ZkNftError::PaymentAccountMissing)?;
+    require_keys_eq!(mint.key(), payment_mint, ZkNftError::InvalidPaymentAccount);
+    let buyer_token_account = ctx
+        .accounts
+        .buyer_token_account
+        .as_ref()
+        .ok_or(ZkNftError::PaymentAccountMissing)?
+        .to_account_info();
+    let seller_token_account = ctx
+        .accounts
+        .seller_token_account
+        .as_ref()
+        .ok_or(ZkNftError::PaymentAccountMissing)?;
+    require_keys_eq!(
+        seller_token_account.owner,
+        ctx.accounts.seller.key(),
+        ZkNftError::InvalidPaymentAccount
+    );
+    let token_program = ctx
+        .accounts
+        .token_program
+        .as_ref()
+        .ok_or(ZkNftError::PaymentAccountMissing)?
+        .to_account_info();
+
+    let royalties_paid = match royalties {
+        Some(royalties) => pay_royalties_in_tokens(
+            &token_program,
+            &buyer_token_account,
+            mint,
+            &buyer,
+            ctx.remaining_accounts,
+            royalties,
+            price,
+        )?,
+        None => 0,
+    };
+    transfer_tokens(
+        token_program,
+        buyer_token_account,
+        mint,
+        seller_token_account.to_account_info(),
+        buyer,
+        price.checked_sub(royalties_paid).unwrap(),
+    )
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+pub struct Buy<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// CHECK: Checked against the asset owner.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+    /// Required when the asset belongs to a group.
+    pub group: Option<Box<Account<'info, Group>>>,
+    /// Required when the group has a recipient policy.
+    pub recipient_policy: Option<Box<Account<'info, RecipientPolicy>>>,
+
+    /// Required, with the token accounts, when the asset is listed for tokens.
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub buyer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub seller_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
@@ -0,0 +1,74 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::BidParams;
+use crate::utils::get_bid_compressed_pda;
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+/// Closes the bid and returns the escrowed lamports to the bidder.
+pub fn cancel_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelBid<'info>>,
+    proof: CompressedProof,
+    bid_params: BidParams,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.bidder.key(),
+        bid_params.data.bidder,
+        ZkNftError::InvalidAuthority
+    );
+
+    let old_bid = get_bid_compressed_pda(ctx.remaining_accounts, &bid_params)?;
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: vec![old_bid],
+        output_compressed_accounts: Vec::new(),
+        compress_or_decompress_lamports: Some(bid_params.data.amount),
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[derive(Accounts, LightTraits)]
+pub struct CancelBid<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    pub bidder: Signer<'info>,
+    /// CHECK: Checked by the light system program.
+    #[account(mut)]
+    pub sol_pool_pda: UncheckedAccount<'info>,
+    /// CHECK: Checked against the bidder.
+    #[account(mut, address = bidder.key())]
+    pub decompression_recipient: UncheckedAccount<'info>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
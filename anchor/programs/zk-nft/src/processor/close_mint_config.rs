@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::{Group, MintConfig};
+
+/// Closes the config once no more assets can be minted from it, and every
+/// asset minted with a placeholder is revealed.
+pub fn close_mint_config<'info>(ctx: Context<CloseMintConfig<'info>>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mint_config = &ctx.accounts.mint_config;
+    require!(mint_config.is_closed(now), ZkNftError::MintNotClosed);
+    require!(
+        mint_config.reveal.is_none() || mint_config.revealed == mint_config.minted,
+        ZkNftError::RevealIncomplete
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseMintConfig<'info> {
+    /// CHECK: This can be any valid public key.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+    pub group_authority: Signer<'info>,
+    #[account(
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+    #[account(
+        mut,
+        has_one = group,
+        close = receiver,
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::{Group, RecipientPolicy};
+
+pub fn close_recipient_policy<'info>(ctx: Context<CloseRecipientPolicy<'info>>) -> Result<()> {
+    ctx.accounts.group.recipient_policy = None;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseRecipientPolicy<'info> {
+    /// CHECK: This can be any valid public key.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+    pub group_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+    #[account(
+        mut,
+        has_one = group,
+        close = receiver,
+    )]
+    pub recipient_policy: Box<Account<'info, RecipientPolicy>>,
+}
@@ -0,0 +1,122 @@
+use crate::constants::{CPI_AUTHORITY_SEED, DECOMPRESSED_MINT_SEED};
+use crate::errors::ZkNftError;
+use crate::state::{BaseData, DelegateRole, OwnerUpdatedEvent, PackedInputCompressedPda, State};
+use crate::utils::get_old_and_new_compressed_pda;
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{self, Burn, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+/// Burns a decompressed asset's token and hands the compressed asset back to
+/// the token holder.
+pub fn compress<'info>(
+    ctx: Context<'_, '_, '_, 'info, Compress<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    base_data: BaseData,
+    base_data_input: PackedInputCompressedPda,
+) -> Result<()> {
+    require!(
+        base_data.state == State::Decompressed,
+        ZkNftError::AssetNotDecompressed
+    );
+
+    token_2022::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    // the token may have changed hands while the asset was decompressed
+    let owner = ctx.accounts.authority.key();
+    if owner != base_data.owner {
+        emit_cpi!(OwnerUpdatedEvent {
+            asset_id: asset_id.into(),
+            owner,
+        });
+    }
+
+    let (old_state, new_state) = get_old_and_new_compressed_pda(
+        asset_id,
+        &base_data_input,
+        &base_data,
+        &BaseData {
+            owner,
+            state: State::Unlocked,
+            delegate: None,
+            delegate_role: DelegateRole::All,
+            delegate_expiry: None,
+            price: None,
+            payment_mint: None,
+        },
+    )?;
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: vec![old_state],
+        output_compressed_accounts: vec![new_state],
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+#[instruction(proof: CompressedProof, asset_id: [u8; 32])]
+pub struct Compress<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    /// Holder of the decompressed asset's token.
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [DECOMPRESSED_MINT_SEED, asset_id.as_ref()],
+        bump,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token2022>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
@@ -0,0 +1,282 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{
+    AssetData, Attribute, Attributes, BaseData, Creator, DelegateRole, Group,
+    MembershipUpdatedEvent, NewAddressParams, OwnerUpdatedEvent, Royalties, State,
+};
+use crate::utils::{get_asset_resource_seed, get_compressed_account_data};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::mpl_token_metadata::types::TokenStandard;
+use anchor_spl::metadata::{self, BurnNft, Metadata, MetadataAccount};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use light_sdk::traits::*;
+use light_sdk::verify::{invoke_cpi, setup_cpi_accounts};
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof,
+    invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+    sdk::{address::derive_address, compressed_account::CompressedAccount},
+    NewAddressParamsPacked, OutputCompressedAccountWithPackedContext,
+};
+
+/// Burns a legacy SPL NFT and recreates it as a compressed asset of the same
+/// owner. A verified Metaplex collection maps onto the group it was assigned to
+/// with `set_group_collection`, and the on-chain name, symbol and uri become the
+/// asset's attributes. The asset id is derived from the mint.
+pub fn compress_from_spl<'info>(
+    ctx: Context<'_, '_, '_, 'info, CompressFromSpl<'info>>,
+    proof: CompressedProof,
+    new_address_params_packed: NewAddressParams,
+    attributes_proof: CompressedProof,
+) -> Result<()> {
+    let metadata = &ctx.accounts.metadata;
+    require!(
+        !matches!(
+            metadata.token_standard,
+            Some(TokenStandard::ProgrammableNonFungible)
+        ),
+        ZkNftError::UnsupportedTokenStandard
+    );
+
+    // only verified collection membership carries over
+    let collection = metadata
+        .collection
+        .as_ref()
+        .filter(|collection| collection.verified)
+        .map(|collection| collection.key);
+    if let Some(collection) = collection {
+        let group = ctx
+            .accounts
+            .group
+            .as_mut()
+            .ok_or(ZkNftError::GroupAccountMissing)?;
+        require!(
+            group.collection == Some(collection),
+            ZkNftError::GroupMismatch
+        );
+        if group.max_size > 0 && group.size >= group.max_size {
+            return Err(ZkNftError::GroupMaxSizeExceeded.into());
+        }
+        group.size = group.size.checked_add(1).unwrap();
+    }
+    let group = match collection {
+        Some(_) => ctx.accounts.group.as_deref(),
+        None => None,
+    };
+
+    let royalties = match &metadata.creators {
+        Some(creators) if !creators.is_empty() => Some(Royalties {
+            basis_points: metadata.seller_fee_basis_points,
+            enforced: false,
+            creators: creators
+                .iter()
+                .map(|creator| Creator {
+                    address: creator.address,
+                    share: creator.share,
+                })
+                .collect(),
+        }),
+        _ => group.and_then(|group| group.royalties.clone()),
+    };
+    if let Some(royalties) = &royalties {
+        royalties.validate()?;
+    }
+
+    let base_data = BaseData {
+        owner: ctx.accounts.authority.key(),
+        state: State::Unlocked,
+        delegate: None,
+        delegate_role: DelegateRole::All,
+        delegate_expiry: None,
+        price: None,
+        payment_mint: None,
+    };
+    let asset_data = AssetData {
+        mutable: metadata.is_mutable,
+        group: group.map(|group| group.key()),
+        // grouped imports are managed like the rest of the group
+        authority: Some(group.map_or(metadata.update_authority, |group| group.authority)),
+        has_attributes: true,
+        has_blob: false,
+        verified: group.is_some(),
+        royalties,
+        non_transferable: group.is_some_and(|group| group.non_transferable),
+        permanent_delegate: group.and_then(|group| group.permanent_delegate),
+        edition: None,
+    };
+    let attributes = Attributes {
+        group: asset_data.group,
+        attributes: [
+            ("name", &metadata.name),
+            ("symbol", &metadata.symbol),
+            ("uri", &metadata.uri),
+        ]
+        .into_iter()
+        .map(|(trait_type, value)| Attribute {
+            trait_type: trait_type.to_string(),
+            // legacy metadata strings are padded with null bytes
+            value: value.trim_end_matches('\0').to_string(),
+        })
+        .collect(),
+    };
+
+    let collection_metadata = ctx
+        .accounts
+        .collection_metadata
+        .as_ref()
+        .map(|collection_metadata| collection_metadata.key());
+    metadata::burn_nft(
+        CpiContext::new(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            BurnNft {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                owner: ctx.accounts.authority.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                token: ctx.accounts.token_account.to_account_info(),
+                edition: ctx.accounts.edition.to_account_info(),
+                spl_token: ctx.accounts.token_program.to_account_info(),
+            },
+        ),
+        collection_metadata,
+    )?;
+
+    let address_merkle_tree = ctx.remaining_accounts
+        [new_address_params_packed.address_merkle_tree_account_index as usize]
+        .key();
+    let new_address_params_for = |seed: [u8; 32]| NewAddressParamsPacked {
+        seed,
+        address_merkle_tree_account_index: new_address_params_packed
+            .address_merkle_tree_account_index,
+        address_queue_account_index: new_address_params_packed.address_queue_account_index,
+        address_merkle_tree_root_index: new_address_params_packed.address_merkle_tree_root_index,
+    };
+    let new_compressed_pda = |address: [u8; 32], data| OutputCompressedAccountWithPackedContext {
+        compressed_account: CompressedAccount {
+            owner: crate::ID,
+            lamports: 0,
+            address: Some(address),
+            data: Some(data),
+        },
+        merkle_tree_index: 0,
+    };
+
+    let base_data_seed = get_asset_resource_seed(b"spl_mint", &ctx.accounts.mint.key().to_bytes())?;
+    let asset_id = derive_address(&address_merkle_tree, &base_data_seed)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+    let asset_data_seed = get_asset_resource_seed(b"asset_data", &asset_id)?;
+    let asset_data_address = derive_address(&address_merkle_tree, &asset_data_seed)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+    let attributes_seed = get_asset_resource_seed(b"attributes", &asset_id)?;
+    let attributes_address = derive_address(&address_merkle_tree, &attributes_seed)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+
+    emit_cpi!(OwnerUpdatedEvent {
+        asset_id: asset_id.into(),
+        owner: base_data.owner,
+    });
+    if let Some(group) = group {
+        emit_cpi!(MembershipUpdatedEvent {
+            asset_id: asset_id.into(),
+            group: group.key(),
+            group_path: group.path.clone(),
+            verified: true,
+        });
+    }
+
+    // make light system program cpis, one per proof
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    for (proof, new_address_params, output_compressed_accounts) in [
+        (
+            proof,
+            vec![
+                new_address_params_for(base_data_seed),
+                new_address_params_for(asset_data_seed),
+            ],
+            vec![
+                new_compressed_pda(asset_id, get_compressed_account_data(&base_data)?),
+                new_compressed_pda(
+                    asset_data_address,
+                    get_compressed_account_data(&asset_data)?,
+                ),
+            ],
+        ),
+        (
+            attributes_proof,
+            vec![new_address_params_for(attributes_seed)],
+            vec![new_compressed_pda(
+                attributes_address,
+                get_compressed_account_data(&attributes)?,
+            )],
+        ),
+    ] {
+        let inputs_struct = InstructionDataInvokeCpi {
+            proof: Some(proof),
+            new_address_params,
+            relay_fee: None,
+            input_compressed_accounts_with_merkle_context: Vec::new(),
+            output_compressed_accounts,
+            compress_or_decompress_lamports: None,
+            is_compress: false,
+            signer_seeds: signer_seeds
+                .iter()
+                .map(|x| x.to_vec())
+                .collect::<Vec<Vec<u8>>>(),
+            cpi_context: None,
+        };
+        let mut inputs: Vec<u8> = Vec::new();
+        InstructionDataInvokeCpi::serialize(&inputs_struct, &mut inputs).unwrap();
+        let cpi_accounts = setup_cpi_accounts(&ctx);
+        invoke_cpi(&ctx, cpi_accounts, inputs, &[&signer_seeds])?;
+    }
+
+    Ok(())
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+pub struct CompressFromSpl<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    /// Owner of the SPL NFT, and of the compressed asset.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = metadata.mint == mint.key() @ ZkNftError::InvalidMetadata,
+    )]
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+    /// CHECK: Master edition, checked by the token metadata program.
+    #[account(mut)]
+    pub edition: UncheckedAccount<'info>,
+    /// CHECK: Required by the token metadata program for sized collections.
+    #[account(mut)]
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+    /// Group the NFT's verified collection is mapped onto.
+    #[account(mut)]
+    pub group: Option<Box<Account<'info, Group>>>,
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
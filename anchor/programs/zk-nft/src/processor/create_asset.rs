@@ -1,16 +1,22 @@
 use std::vec;
 
 use crate::errors::ZkNftError;
-use crate::state::{AssetData, BaseData, DelegateRole, Group, OwnerUpdatedEvent};
-use crate::utils::get_asset_resource_seed;
+use crate::state::{
+    AssetData, BaseData, DelegateRole, Group, MembershipUpdatedEvent, OwnerUpdatedEvent,
+};
+use crate::utils::{content_type_essence, get_asset_resource_seed, validate_content_type};
 use crate::{constants::CPI_AUTHORITY_SEED, state::State};
-use crate::{Attribute, Attributes, Blob, NewAddressParams};
+use crate::{
+    Attribute, Attributes, Blob, NewAddressParams, PackedInputCompressedPda, Royalties, SharedBlob,
+    UploadedBlob,
+};
 use account_compression::{program::AccountCompression, RegisteredProgram};
 use anchor_lang::{prelude::*, Discriminator};
 use light_hasher::{DataHasher, Poseidon};
 use light_sdk::traits::*;
 use light_sdk::verify::{invoke_cpi, setup_cpi_accounts};
 use light_sdk::{light_accounts, LightTraits};
+use light_system_program::sdk::compressed_account::PackedCompressedAccountWithMerkleContext;
 use light_system_program::InstructionDataInvokeCpi;
 use light_system_program::{
     invoke::processor::CompressedProof,
@@ -30,11 +36,21 @@ pub fn create_asset<'info>(
     new_address_params_packed: NewAddressParams,
     blob_params: Option<BlobParams>,
     attributes_params: Option<AttributesParams>,
+    royalties: Option<Royalties>,
+    non_transferable: bool,
 ) -> Result<()> {
+    // Membership is verified at mint: the group authority must sign for every
+    // asset minted into its group, and is the asset authority if one is set.
     if let Some(group) = &mut ctx.accounts.group {
+        let group_authority = ctx
+            .accounts
+            .group_authority
+            .as_ref()
+            .ok_or(ZkNftError::GroupAuthorityMissing)?;
         require_keys_eq!(
             group.authority,
-            ctx.accounts.group_authority.clone().unwrap().key()
+            group_authority.key(),
+            ZkNftError::InvalidAuthority
         );
         if group.max_size > 0 && group.size >= group.max_size {
             return Err(ZkNftError::GroupMaxSizeExceeded.into());
@@ -43,6 +59,58 @@ pub fn create_asset<'info>(
         group.size = group.size.checked_add(1).unwrap();
     }
 
+    if let Some(blob_params) = &blob_params {
+        validate_content_type(&blob_params.data.content_type)?;
+        if let Some(group) = &ctx.accounts.group {
+            require!(
+                group.allowed_content_types.is_empty()
+                    || group
+                        .allowed_content_types
+                        .contains(&content_type_essence(&blob_params.data.content_type)),
+                ZkNftError::ContentTypeNotAllowed
+            );
+        }
+    }
+
+    // the buffer's final authority, not its uploaders, has to approve linking it
+    if let Some(uploaded_blob) = &ctx.accounts.uploaded_blob {
+        require!(
+            uploaded_blob.authority == ctx.accounts.payer.key()
+                || ctx
+                    .accounts
+                    .group_authority
+                    .as_ref()
+                    .map(|signer| signer.key())
+                    == Some(uploaded_blob.authority),
+            ZkNftError::InvalidUploadedBlobAuthority
+        );
+    }
+
+    // a blob claiming an uploaded buffer must match it
+    let blob_uploaded_blob = blob_params
+        .as_ref()
+        .and_then(|blob_params| blob_params.data.uploaded_blob);
+    require!(
+        blob_uploaded_blob
+            == ctx
+                .accounts
+                .uploaded_blob
+                .as_ref()
+                .map(|account| account.key()),
+        ZkNftError::UploadedBlobMismatch
+    );
+    if let (Some(blob_params), Some(uploaded_blob)) = (&blob_params, &ctx.accounts.uploaded_blob) {
+        require!(
+            uploaded_blob.finalized,
+            ZkNftError::UploadedBlobNotFinalized
+        );
+        require!(
+            blob_params.data.hash == Some(uploaded_blob.hash)
+                && blob_params.data.content_encoding == uploaded_blob.content_encoding,
+            ZkNftError::UploadedBlobMismatch
+        );
+    }
+
     if let Some(authority) = &ctx.accounts.authority {
         if let Some(group_authority) = &ctx.accounts.group_authority {
             require_keys_eq!(group_authority.key(), authority.key());
@@ -61,6 +129,32 @@ pub fn create_asset<'info>(
         asset_id: asset_id.into(),
         owner: *ctx.accounts.recipient.key,
     });
+    if let Some(group) = &ctx.accounts.group {
+        emit_cpi!(MembershipUpdatedEvent {
+            asset_id: asset_id.into(),
+            group: group.key(),
+            group_path: group.path.clone(),
+            verified: true,
+        });
+    }
+
+    // asset royalties override the group defaults
+    let royalties = royalties.or_else(|| {
+        ctx.accounts
+            .group
+            .as_ref()
+            .and_then(|group| group.royalties.clone())
+    });
+    if let Some(royalties) = &royalties {
+        royalties.validate()?;
+    }
+
+    let non_transferable = non_transferable
+        || ctx
+            .accounts
+            .group
+            .as_ref()
+            .is_some_and(|group| group.non_transferable);
 
     create_compressed_pdas(
         &ctx,
@@ -70,6 +164,8 @@ pub fn create_asset<'info>(
         base_data_seed,
         blob_params,
         attributes_params,
+        royalties,
+        non_transferable,
     )?;
 
     Ok(())
@@ -83,6 +179,8 @@ fn create_compressed_pdas<'info>(
     base_data_seed: [u8; 32],
     blob_params: Option<BlobParams>,
     attributes_params: Option<AttributesParams>,
+    royalties: Option<Royalties>,
+    non_transferable: bool,
 ) -> Result<()> {
     let mut new_address_params: Vec<NewAddressParamsPacked> = Vec::new();
     let mut output_compressed_accounts: Vec<OutputCompressedAccountWithPackedContext> = Vec::new();
@@ -93,6 +191,9 @@ fn create_compressed_pdas<'info>(
         state: State::Unlocked,
         delegate: None,
         delegate_role: DelegateRole::All,
+        delegate_expiry: None,
+        price: None,
+        payment_mint: None,
     };
 
     let base_data_compressed_account_data = CompressedAccountData {
@@ -130,9 +231,18 @@ fn create_compressed_pdas<'info>(
             Some(group) => Some(group.key()),
             None => None,
         },
-        has_attributes: false,
+        has_attributes: attributes_params.is_some(),
         has_blob: blob_params.is_some(),
         mutable: true,
+        verified: ctx.accounts.group.is_some(),
+        royalties,
+        non_transferable,
+        permanent_delegate: ctx
+            .accounts
+            .group
+            .as_ref()
+            .and_then(|group| group.permanent_delegate),
+        edition: None,
     };
 
     let asset_data_compressed_account_data = CompressedAccountData {
@@ -229,12 +339,42 @@ fn create_compressed_pdas<'info>(
                 .address_merkle_tree_root_index,
         };
 
+        let mut input_compressed_accounts_with_merkle_context = Vec::new();
+        let mut output_compressed_accounts = vec![blob_compressed_pda];
+
+        // Link to the shared blob record, proving it exists with the same content
+        if blob_params.data.shared_blob_creator.is_some() {
+            let shared_blob = blob_params
+                .data
+                .to_shared_blob()
+                .ok_or(ZkNftError::SharedBlobHashMissing)?;
+            let shared_blob_input = blob_params
+                .shared_blob_input
+                .ok_or(ZkNftError::SharedBlobInputMissing)?;
+            let shared_blob_seed = SharedBlob::seed(&shared_blob.creator, &shared_blob.hash)?;
+            let shared_blob_address = derive_address(
+                &ctx.remaining_accounts
+                    [new_address_params_packed.address_merkle_tree_account_index as usize]
+                    .key(),
+                &shared_blob_seed,
+            )
+            .map_err(|_| ProgramError::InvalidArgument)?;
+
+            let (old_shared_blob, new_shared_blob) = get_unchanged_shared_blob_compressed_pda(
+                shared_blob_address,
+                &shared_blob,
+                &shared_blob_input,
+            )?;
+            input_compressed_accounts_with_merkle_context.push(old_shared_blob);
+            output_compressed_accounts.push(new_shared_blob);
+        }
+
         let inputs_struct = InstructionDataInvokeCpi {
             proof: Some(blob_params.proof),
             new_address_params: vec![blob_new_address_params],
             relay_fee: None,
-            input_compressed_accounts_with_merkle_context: Vec::new(),
-            output_compressed_accounts: vec![blob_compressed_pda],
+            input_compressed_accounts_with_merkle_context,
+            output_compressed_accounts,
             compress_or_decompress_lamports: None,
             is_compress: false,
             signer_seeds: signer_seeds
@@ -273,7 +413,7 @@ fn create_compressed_pdas<'info>(
                 lamports: 0,
                 address: Some(attributes_address),
                 data: Some(CompressedAccountData {
-                    discriminator: Blob::discriminator(),
+                    discriminator: Attributes::discriminator(),
                     data: attributes.try_to_vec().unwrap(),
                     data_hash: attributes.hash::<Poseidon>().map_err(ProgramError::from)?,
                 }),
@@ -313,10 +453,44 @@ fn create_compressed_pdas<'info>(
     Ok(())
 }
 
+fn get_unchanged_shared_blob_compressed_pda(
+    shared_blob_address: [u8; 32],
+    shared_blob: &SharedBlob,
+    shared_blob_input: &PackedInputCompressedPda,
+) -> Result<(
+    PackedCompressedAccountWithMerkleContext,
+    OutputCompressedAccountWithPackedContext,
+)> {
+    let compressed_account = CompressedAccount {
+        owner: crate::ID,
+        lamports: 0,
+        address: Some(shared_blob_address),
+        data: Some(CompressedAccountData {
+            discriminator: SharedBlob::discriminator(),
+            data: shared_blob.try_to_vec().unwrap(),
+            data_hash: shared_blob.hash::<Poseidon>().map_err(ProgramError::from)?,
+        }),
+    };
+
+    let old_compressed_account_with_context = PackedCompressedAccountWithMerkleContext {
+        compressed_account: compressed_account.clone(),
+        merkle_context: shared_blob_input.merkle_context,
+        root_index: shared_blob_input.root_index,
+    };
+    let new_compressed_account = OutputCompressedAccountWithPackedContext {
+        compressed_account,
+        merkle_tree_index: shared_blob_input.merkle_context.merkle_tree_pubkey_index,
+    };
+
+    Ok((old_compressed_account_with_context, new_compressed_account))
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BlobParams {
     pub data: Blob,
     pub proof: CompressedProof,
+    /// Required when `data.shared_blob_creator` is set.
+    pub shared_blob_input: Option<PackedInputCompressedPda>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -339,6 +513,7 @@ pub struct CreateAsset<'info> {
     pub recipient: UncheckedAccount<'info>,
     #[account(mut)]
     pub group: Option<Box<Account<'info, Group>>>,
+    pub uploaded_blob: Option<Account<'info, UploadedBlob>>,
 
     #[authority]
     #[account(
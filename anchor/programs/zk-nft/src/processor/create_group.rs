@@ -1,15 +1,54 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::MAX_GROUP_DEPTH;
+use crate::errors::ZkNftError;
 use crate::state::Group;
 
+/// Creates a group, nested in `parent` when its authority signs. The parent
+/// can't be changed later, so the paths of nested groups stay valid.
 pub fn create_group<'info>(
     ctx: Context<CreateGroup<'info>>,
     max_size: u64,
+    non_transferable: bool,
 ) -> Result<()> {
+    let (parent, path) = match &ctx.accounts.parent {
+        Some(parent) => {
+            let parent_authority = ctx
+                .accounts
+                .parent_authority
+                .as_ref()
+                .ok_or(ZkNftError::ParentAuthorityMissing)?;
+            require_keys_eq!(
+                parent_authority.key(),
+                parent.authority,
+                ZkNftError::InvalidAuthority
+            );
+            require!(
+                parent.path.len() < MAX_GROUP_DEPTH,
+                ZkNftError::GroupTooDeep
+            );
+            let mut path = parent.path.clone();
+            path.push(parent.key());
+            (Some(parent.key()), path)
+        }
+        None => (None, Vec::new()),
+    };
+
     let group = &mut ctx.accounts.group;
     group.size = 0;
     group.max_size = max_size;
     group.authority = ctx.accounts.group_authority.key();
+    group.allowed_content_types = Vec::new();
+    group.royalties = None;
+    group.non_transferable = non_transferable;
+    group.permanent_delegate = None;
+    group.frozen = false;
+    group.transfer_hook_program = None;
+    group.recipient_policy = None;
+    group.collection = None;
+    group.parent = parent;
+    group.path = path;
+    group.mint_configs = 0;
 
     Ok(())
 }
@@ -22,5 +61,7 @@ pub struct CreateGroup<'info> {
     pub group_authority: Signer<'info>,
     #[account(init, payer = payer, space = 8 + Group::INIT_SPACE)]
     pub group: Box<Account<'info, Group>>,
+    pub parent: Option<Box<Account<'info, Group>>>,
+    pub parent_authority: Option<Signer<'info>>,
     pub system_program: Program<'info, System>,
 }
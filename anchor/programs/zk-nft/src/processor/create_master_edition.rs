@@ -0,0 +1,96 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{AssetData, AssetDataParams, BaseData, Edition, PackedInputCompressedPda};
+use crate::utils::{derive_asset_resource_address, get_old_and_new_compressed_pda};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+/// Makes the asset a master edition, printable up to `max_supply` times, or
+/// without limit when it is not set. Signed by the asset authority, assets
+/// without one cannot be printed.
+pub fn create_master_edition<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateMasterEdition<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    base_data: BaseData,
+    base_data_input: PackedInputCompressedPda,
+    asset_data_params: AssetDataParams,
+    max_supply: Option<u64>,
+) -> Result<()> {
+    let asset_data = &asset_data_params.data;
+    require!(
+        asset_data.authority == Some(ctx.accounts.authority.key()),
+        ZkNftError::InvalidAuthority
+    );
+    require!(asset_data.mutable, ZkNftError::AssetNotMutable);
+    require!(asset_data.edition.is_none(), ZkNftError::AssetHasEdition);
+
+    // base data is read only, but has to be consumed to prove the owner
+    let (old_state, new_state) =
+        get_old_and_new_compressed_pda(asset_id, &base_data_input, &base_data, &base_data)?;
+    let asset_data_address = derive_asset_resource_address(
+        ctx.remaining_accounts,
+        asset_data_params.address_merkle_tree_account_index,
+        b"asset_data",
+        &asset_id,
+    )?;
+    let (old_asset_data, new_asset_data) = get_old_and_new_compressed_pda(
+        asset_data_address,
+        &asset_data_params.input,
+        asset_data,
+        &AssetData {
+            edition: Some(Edition::Master {
+                supply: 0,
+                max_supply,
+            }),
+            ..asset_data.clone()
+        },
+    )?;
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: vec![old_state, old_asset_data],
+        output_compressed_accounts: vec![new_state, new_asset_data],
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[derive(Accounts, LightTraits)]
+pub struct CreateMasterEdition<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::{Group, MintConfig, MintConfigParams};
+
+pub fn create_mint_config<'info>(
+    ctx: Context<CreateMintConfig<'info>>,
+    params: MintConfigParams,
+) -> Result<()> {
+    let group = &mut ctx.accounts.group;
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.group = group.key();
+    mint_config.id = group.mint_configs;
+    mint_config.address_merkle_tree = ctx.accounts.address_merkle_tree.key();
+    mint_config.minted = 0;
+    mint_config.reveal_slot = None;
+    mint_config.reveal_offset = None;
+    mint_config.revealed = 0;
+    group.mint_configs = group.mint_configs.checked_add(1).unwrap();
+    mint_config.set_params(params)
+}
+
+#[derive(Accounts)]
+pub struct CreateMintConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub group_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MintConfig::INIT_SPACE,
+        seeds = [
+            b"mint_config",
+            group.key().as_ref(),
+            &group.mint_configs.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+    /// CHECK: Any address tree, the config creates all its accounts in it.
+    #[account(owner = account_compression::ID)]
+    pub address_merkle_tree: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
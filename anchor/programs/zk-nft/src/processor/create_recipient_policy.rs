@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::{Group, RecipientPolicy, RecipientPolicyMode};
+
+pub fn create_recipient_policy<'info>(
+    ctx: Context<CreateRecipientPolicy<'info>>,
+    mode: RecipientPolicyMode,
+    root: [u8; 32],
+) -> Result<()> {
+    let recipient_policy = &mut ctx.accounts.recipient_policy;
+    recipient_policy.group = ctx.accounts.group.key();
+    recipient_policy.mode = mode;
+    recipient_policy.root = root;
+
+    ctx.accounts.group.recipient_policy = Some(recipient_policy.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateRecipientPolicy<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub group_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RecipientPolicy::INIT_SPACE,
+        seeds = [b"recipient_policy", group.key().as_ref()],
+        bump,
+    )]
+    pub recipient_policy: Box<Account<'info, RecipientPolicy>>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,104 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{NewAddressParams, SharedBlob};
+use crate::utils::validate_content_type;
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::{prelude::*, Discriminator};
+use light_hasher::{DataHasher, Poseidon};
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof,
+    invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+    sdk::{
+        address::derive_address,
+        compressed_account::{CompressedAccount, CompressedAccountData},
+    },
+    NewAddressParamsPacked, OutputCompressedAccountWithPackedContext,
+};
+
+pub fn create_shared_blob<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateSharedBlob<'info>>,
+    proof: CompressedProof,
+    shared_blob: SharedBlob,
+    new_address_params_packed: NewAddressParams,
+) -> Result<()> {
+    require_keys_eq!(
+        shared_blob.creator,
+        ctx.accounts.creator.key(),
+        ZkNftError::InvalidAuthority
+    );
+    validate_content_type(&shared_blob.content_type)?;
+
+    let shared_blob_seed = SharedBlob::seed(&shared_blob.creator, &shared_blob.hash)?;
+    let shared_blob_address = derive_address(
+        &ctx.remaining_accounts
+            [new_address_params_packed.address_merkle_tree_account_index as usize]
+            .key(),
+        &shared_blob_seed,
+    )
+    .map_err(|_| ProgramError::InvalidArgument)?;
+
+    let shared_blob_compressed_pda = OutputCompressedAccountWithPackedContext {
+        compressed_account: CompressedAccount {
+            owner: crate::ID,
+            lamports: 0,
+            address: Some(shared_blob_address),
+            data: Some(CompressedAccountData {
+                discriminator: SharedBlob::discriminator(),
+                data: shared_blob.try_to_vec().unwrap(),
+                data_hash: shared_blob.hash::<Poseidon>().map_err(ProgramError::from)?,
+            }),
+        },
+        merkle_tree_index: 0,
+    };
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: vec![NewAddressParamsPacked {
+            seed: shared_blob_seed,
+            address_merkle_tree_account_index: new_address_params_packed
+                .address_merkle_tree_account_index,
+            address_queue_account_index: new_address_params_packed.address_queue_account_index,
+            address_merkle_tree_root_index: new_address_params_packed
+                .address_merkle_tree_root_index,
+        }],
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: Vec::new(),
+        output_compressed_accounts: vec![shared_blob_compressed_pda],
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[derive(Accounts, LightTraits)]
+pub struct CreateSharedBlob<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    pub creator: Signer<'info>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
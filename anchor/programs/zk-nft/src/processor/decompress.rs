@@ -0,0 +1,353 @@
+use crate::constants::{CPI_AUTHORITY_SEED, DECOMPRESSED_MINT_SEED};
+use crate::errors::ZkNftError;
+use crate::state::{
+    AssetAttributesParams, AssetData, AssetDataParams, AssetDecompressedEvent, BaseData,
+    DelegateRole, Edition, Group, PackedInputCompressedPda, State,
+};
+use crate::utils::{
+    get_old_and_new_compressed_pda, get_unchanged_asset_data_compressed_pda,
+    get_unchanged_attributes_compressed_pda,
+};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{metadata_pointer, ExtensionType},
+};
+use anchor_spl::token_2022::{self, InitializeMint2, MintTo, Token2022};
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
+
+/// Mints the asset as a Token-2022 NFT to its owner and marks the compressed
+/// asset as decompressed. The mint is created on the first decompression, with
+/// its metadata, attributes included, stored on the mint itself.
+///
+/// Group rules are only checked here. Freezing the group, or adding a transfer
+/// hook or recipient policy to it, does not reach assets already held as
+/// tokens until they are compressed again.
+pub fn decompress<'info>(
+    ctx: Context<'_, '_, '_, 'info, Decompress<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    base_data: BaseData,
+    base_data_input: PackedInputCompressedPda,
+    asset_data_params: AssetDataParams,
+    attributes_params: Option<AssetAttributesParams>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let asset_data = &asset_data_params.data;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        base_data.owner,
+        ZkNftError::InvalidAuthority
+    );
+    require!(
+        base_data.effective_state(now) == State::Unlocked,
+        ZkNftError::AssetIsLocked
+    );
+
+    // the token program cannot enforce royalties or group transfer rules
+    require!(
+        !asset_data
+            .royalties
+            .as_ref()
+            .is_some_and(|royalties| royalties.enforced),
+        ZkNftError::DecompressionRestricted
+    );
+    if let Some(asset_group) = asset_data.group {
+        let group = ctx
+            .accounts
+            .group
+            .as_ref()
+            .ok_or(ZkNftError::GroupAccountMissing)?;
+        require_keys_eq!(group.key(), asset_group, ZkNftError::GroupMismatch);
+        require!(!group.frozen, ZkNftError::GroupFrozen);
+        require!(
+            group.transfer_hook_program.is_none() && group.recipient_policy.is_none(),
+            ZkNftError::DecompressionRestricted
+        );
+    }
+    require!(
+        !asset_data.has_attributes || attributes_params.is_some(),
+        ZkNftError::AttributesMissing
+    );
+
+    if ctx.accounts.mint.data_is_empty() {
+        create_decompressed_mint(&ctx, asset_id, asset_data, attributes_params.as_ref())?;
+    }
+
+    associated_token::create_idempotent(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: ctx.accounts.payer.to_account_info(),
+            associated_token: ctx.accounts.token_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.cpi_authority_pda.to_account_info(),
+            },
+            &[&signer_seeds],
+        ),
+        1,
+    )?;
+
+    emit_cpi!(AssetDecompressedEvent {
+        asset_id: asset_id.into(),
+        mint: ctx.accounts.mint.key(),
+    });
+
+    let (old_state, new_state) = get_old_and_new_compressed_pda(
+        asset_id,
+        &base_data_input,
+        &base_data,
+        &BaseData {
+            owner: base_data.owner,
+            state: State::Decompressed,
+            delegate: None,
+            delegate_role: DelegateRole::All,
+            delegate_expiry: None,
+            price: None,
+            payment_mint: None,
+        },
+    )?;
+    let (old_asset_data, new_asset_data) = get_unchanged_asset_data_compressed_pda(
+        ctx.remaining_accounts,
+        &asset_id,
+        &asset_data_params,
+    )?;
+    let mut input_compressed_accounts_with_merkle_context = vec![old_state, old_asset_data];
+    let mut output_compressed_accounts = vec![new_state, new_asset_data];
+    if let Some(attributes_params) = &attributes_params {
+        // prints carry the attributes of their master
+        let attributes_asset_id = match asset_data.edition {
+            Some(Edition::Print { parent, .. }) => parent,
+            _ => asset_id,
+        };
+        let (old_attributes, new_attributes) = get_unchanged_attributes_compressed_pda(
+            ctx.remaining_accounts,
+            &attributes_asset_id,
+            attributes_params,
+        )?;
+        input_compressed_accounts_with_merkle_context.push(old_attributes);
+        output_compressed_accounts.push(new_attributes);
+    }
+
+    // make light system program cpi
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context,
+        output_compressed_accounts,
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+/// Creates the asset's mint with the metadata pointer and token metadata
+/// extensions, and the permanent delegate and non-transferable extensions
+/// mirroring the asset. The cpi authority is mint, freeze and update authority.
+///
+/// The metadata only holds what the compressed asset proves, the asset has no
+/// name, symbol or uri of its own, so those are left empty.
+fn create_decompressed_mint<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Decompress<'info>>,
+    asset_id: [u8; 32],
+    asset_data: &AssetData,
+    attributes_params: Option<&AssetAttributesParams>,
+) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let cpi_authority = ctx.accounts.cpi_authority_pda.key();
+    let token_program_id = ctx.accounts.token_program.key();
+
+    let mut additional_metadata =
+        vec![("asset_id".to_string(), Pubkey::from(asset_id).to_string())];
+    if let (Some(group), true) = (asset_data.group, asset_data.verified) {
+        additional_metadata.push(("group".to_string(), group.to_string()));
+    }
+    if let Some(attributes_params) = attributes_params {
+        additional_metadata.extend(
+            attributes_params
+                .data
+                .attributes
+                .iter()
+                .map(|attribute| (attribute.trait_type.clone(), attribute.value.clone())),
+        );
+    }
+    let metadata = TokenMetadata {
+        update_authority: Some(cpi_authority).try_into()?,
+        mint: mint.key(),
+        name: String::new(),
+        symbol: String::new(),
+        uri: String::new(),
+        additional_metadata,
+    };
+
+    let mut extensions = vec![ExtensionType::MetadataPointer];
+    if asset_data.permanent_delegate.is_some() {
+        extensions.push(ExtensionType::PermanentDelegate);
+    }
+    if asset_data.non_transferable {
+        extensions.push(ExtensionType::NonTransferable);
+    }
+    let space =
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions)?;
+    // token metadata is reallocated into the mint, so its rent is paid upfront
+    let lamports = Rent::get()?.minimum_balance(space + metadata.tlv_size_of()?);
+
+    let mint_bump = &[ctx.bumps.mint];
+    let mint_seeds: [&[u8]; 3] = [DECOMPRESSED_MINT_SEED, asset_id.as_slice(), mint_bump];
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: mint.clone(),
+            },
+            &[&mint_seeds],
+        ),
+        lamports,
+        space as u64,
+        &token_program_id,
+    )?;
+
+    invoke(
+        &metadata_pointer::instruction::initialize(
+            &token_program_id,
+            mint.key,
+            Some(cpi_authority),
+            Some(mint.key()),
+        )?,
+        &[mint.clone()],
+    )?;
+    if let Some(permanent_delegate) = asset_data.permanent_delegate {
+        invoke(
+            &spl_token_2022::instruction::initialize_permanent_delegate(
+                &token_program_id,
+                mint.key,
+                &permanent_delegate,
+            )?,
+            &[mint.clone()],
+        )?;
+    }
+    if asset_data.non_transferable {
+        invoke(
+            &spl_token_2022::instruction::initialize_non_transferable_mint(
+                &token_program_id,
+                mint.key,
+            )?,
+            &[mint.clone()],
+        )?;
+    }
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 { mint: mint.clone() },
+        ),
+        0,
+        &cpi_authority,
+        Some(&cpi_authority),
+    )?;
+
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let cpi_authority_info = ctx.accounts.cpi_authority_pda.to_account_info();
+    invoke_signed(
+        &spl_token_metadata_interface::instruction::initialize(
+            &token_program_id,
+            mint.key,
+            &cpi_authority,
+            mint.key,
+            &cpi_authority,
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+        ),
+        &[
+            mint.clone(),
+            cpi_authority_info.clone(),
+            mint.clone(),
+            cpi_authority_info.clone(),
+        ],
+        &[&signer_seeds],
+    )?;
+    for (key, value) in metadata.additional_metadata {
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::update_field(
+                &token_program_id,
+                mint.key,
+                &cpi_authority,
+                Field::Key(key),
+                value,
+            ),
+            &[mint.clone(), cpi_authority_info.clone()],
+            &[&signer_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+#[instruction(proof: CompressedProof, asset_id: [u8; 32])]
+pub struct Decompress<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// Required when the asset belongs to a group.
+    pub group: Option<Box<Account<'info, Group>>>,
+    /// CHECK: Created on the asset's first decompression.
+    #[account(
+        mut,
+        seeds = [DECOMPRESSED_MINT_SEED, asset_id.as_ref()],
+        bump,
+    )]
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: The authority's associated token account, created if needed.
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
@@ -1,10 +1,19 @@
 use anchor_lang::{prelude::*, Discriminator};
 
-use crate::{constants::UPLOADED_BLOB_BUFFER_START, UploadedBlob};
+use crate::constants::{MAX_BLOB_UPLOADERS, UPLOADED_BLOB_BUFFER_START};
+use crate::{ContentEncoding, UploadedBlob};
 
-pub fn init_blob_upload(ctx: Context<InitBlobUpload>, _total_bytes: u32) -> Result<()> {
+pub fn init_blob_upload(
+    ctx: Context<InitBlobUpload>,
+    _total_bytes: u32,
+    content_encoding: ContentEncoding,
+) -> Result<()> {
     let uploaded_blob = UploadedBlob {
         authority: ctx.accounts.authority.key(),
+        content_encoding,
+        uploaders: [Pubkey::default(); MAX_BLOB_UPLOADERS],
+        finalized: false,
+        hash: [0; 32],
     };
     let mut struct_data = UploadedBlob::discriminator().try_to_vec().unwrap();
     struct_data.append(&mut uploaded_blob.try_to_vec().unwrap());
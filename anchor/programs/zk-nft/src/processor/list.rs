@@ -0,0 +1,93 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{BaseData, DelegateRole, PackedInputCompressedPda, State};
+use crate::utils::get_old_and_new_compressed_pda;
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+/// Lists the asset for sale at `price` lamports, or `payment_mint` tokens when
+/// set. The program becomes the transfer delegate and the asset stays locked
+/// until it is bought or delisted.
+pub fn list<'info>(
+    ctx: Context<'_, '_, '_, 'info, List<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    base_data: BaseData,
+    base_data_input: PackedInputCompressedPda,
+    price: u64,
+    payment_mint: Option<Pubkey>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        base_data.owner,
+        ZkNftError::InvalidAuthority
+    );
+    require!(
+        base_data.effective_state(now) == State::Unlocked,
+        ZkNftError::AssetIsLocked
+    );
+    require!(price > 0, ZkNftError::InvalidPrice);
+
+    let (old_state, new_state) = get_old_and_new_compressed_pda(
+        asset_id,
+        &base_data_input,
+        &base_data,
+        &BaseData {
+            owner: base_data.owner,
+            state: State::LockedByDelegate,
+            delegate: Some(ctx.accounts.cpi_authority_pda.key()),
+            delegate_role: DelegateRole::Transfer,
+            delegate_expiry: None,
+            price: Some(price),
+            payment_mint,
+        },
+    )?;
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: vec![old_state],
+        output_compressed_accounts: vec![new_state],
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[derive(Accounts, LightTraits)]
+pub struct List<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
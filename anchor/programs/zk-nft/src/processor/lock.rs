@@ -0,0 +1,116 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{
+    AssetDataParams, BaseData, PackedInputCompressedPda, PermanentDelegateAction,
+    PermanentDelegateEvent, State,
+};
+use crate::utils::{get_old_and_new_compressed_pda, get_unchanged_asset_data_compressed_pda};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+/// Locks the asset. The lock state records who locked it, which is the only
+/// party, besides the permanent delegate, able to unlock it.
+pub fn lock<'info>(
+    ctx: Context<'_, '_, '_, 'info, Lock<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    base_data: BaseData,
+    base_data_input: PackedInputCompressedPda,
+    asset_data_params: Option<AssetDataParams>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        base_data.effective_state(now) == State::Unlocked,
+        ZkNftError::AssetIsLocked
+    );
+
+    let authority = ctx.accounts.authority.key();
+    let state = if asset_data_params
+        .as_ref()
+        .is_some_and(|params| params.data.permanent_delegate == Some(authority))
+    {
+        emit_cpi!(PermanentDelegateEvent {
+            asset_id: asset_id.into(),
+            permanent_delegate: authority,
+            action: PermanentDelegateAction::Lock,
+        });
+        State::LockedByPermanentDelegate
+    } else if authority == base_data.owner {
+        State::LockedByAuthority
+    } else {
+        require!(
+            Some(authority) == base_data.active_delegate(now) && base_data.delegate_role.can_lock(),
+            ZkNftError::InvalidAuthority
+        );
+        State::LockedByDelegate
+    };
+
+    let (old_state, new_state) = get_old_and_new_compressed_pda(
+        asset_id,
+        &base_data_input,
+        &base_data,
+        &BaseData {
+            state,
+            ..base_data.clone()
+        },
+    )?;
+    let mut input_compressed_accounts_with_merkle_context = vec![old_state];
+    let mut output_compressed_accounts = vec![new_state];
+    if let Some(asset_data_params) = &asset_data_params {
+        let (old_asset_data, new_asset_data) = get_unchanged_asset_data_compressed_pda(
+            ctx.remaining_accounts,
+            &asset_id,
+            asset_data_params,
+        )?;
+        input_compressed_accounts_with_merkle_context.push(old_asset_data);
+        output_compressed_accounts.push(new_asset_data);
+    }
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context,
+        output_compressed_accounts,
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+pub struct Lock<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
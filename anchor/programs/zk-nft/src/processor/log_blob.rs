@@ -1,30 +1,45 @@
+use crate::constants::UPLOADED_BLOB_BUFFER_START;
+use crate::errors::ZkNftError;
+use crate::{BlobChunkHeader, BlobLoggedEvent, UploadedBlob};
 use anchor_lang::prelude::*;
 #[allow(unused_imports)]
 use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::pubkey::Pubkey;
-#[allow(unused_imports)]
-use crate::constants::UPLOADED_BLOB_BUFFER_START;
-use crate::UploadedBlob;
 
-#[allow(dead_code)]
 const MAX_CPI_BYTES: usize = 10_240;
 
-#[allow(unused_variables)]
+pub const MAX_BLOB_CHUNK_BYTES: usize = MAX_CPI_BYTES - BlobChunkHeader::LEN;
+
 pub fn log_blob(ctx: Context<LogBlob>) -> Result<()> {
+    require!(
+        !ctx.accounts.uploaded_blob.finalized,
+        ZkNftError::UploadedBlobFinalized
+    );
+
     let account_info = ctx.accounts.uploaded_blob.to_account_info();
     let account_data = account_info.data.borrow();
 
-    #[allow(dead_code)]
+    let blob = &account_data[UPLOADED_BLOB_BUFFER_START..];
+    let hash = anchor_lang::solana_program::hash::hash(blob).to_bytes();
+    let total_chunks = blob.len().div_ceil(MAX_BLOB_CHUNK_BYTES) as u32;
+
     #[cfg(target_os = "solana")]
     {
-        for i in (UPLOADED_BLOB_BUFFER_START..account_data.len()).step_by(MAX_CPI_BYTES) {
-            let left = i;
-            let right = std::cmp::min(i + MAX_CPI_BYTES, account_data.len());
-
+        for (index, chunk) in blob.chunks(MAX_BLOB_CHUNK_BYTES).enumerate() {
             let pre_cpi_pos = light_heap::GLOBAL_ALLOCATOR.get_heap_pos();
 
+            let mut payload = BlobChunkHeader {
+                uploaded_blob: account_info.key(),
+                index: index as u32,
+                total_chunks,
+                hash,
+            }
+            .try_to_vec()
+            .unwrap();
+            payload.extend_from_slice(chunk);
+
             invoke(
-                &spl_noop::instruction(account_data[left..right].to_vec()),
+                &spl_noop::instruction(payload),
                 &[ctx.accounts.noop_program.to_account_info()],
             )?;
 
@@ -32,13 +47,27 @@ pub fn log_blob(ctx: Context<LogBlob>) -> Result<()> {
         }
     }
 
+    emit_cpi!(BlobLoggedEvent {
+        uploaded_blob: account_info.key(),
+        total_chunks,
+        total_bytes: blob.len() as u32,
+        hash,
+    });
+
+    drop(account_data);
+    let uploaded_blob = &mut ctx.accounts.uploaded_blob;
+    uploaded_blob.finalized = true;
+    uploaded_blob.hash = hash;
+
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct LogBlob<'info> {
     #[account(
-        has_one = authority,
+        mut,
+        constraint = uploaded_blob.can_upload(&authority.key()) @ ZkNftError::InvalidAuthority,
     )]
     pub uploaded_blob: Account<'info, UploadedBlob>,
     pub authority: Signer<'info>,
@@ -0,0 +1,337 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{
+    AllowlistProof, AssetData, Attributes, BaseData, DelegateRole, Group, MembershipUpdatedEvent,
+    MintConfig, MintRecord, MintRecordParams, NewAddressParams, OwnerUpdatedEvent, State,
+};
+use crate::utils::{
+    get_asset_resource_seed, get_new_compressed_pda, get_old_and_new_compressed_pda,
+    transfer_lamports, transfer_tokens,
+};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use light_sdk::traits::*;
+use light_sdk::verify::{invoke_cpi, setup_cpi_accounts};
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram, sdk::address::derive_address,
+};
+
+/// Mints the config's next asset into its group for the config price, paid in
+/// lamports or in the config's payment mint tokens. The
+/// asset id is derived from the config and the mint index, and the config is
+/// the asset authority. Minting in an allowlist `phase` takes the buyer's
+/// `allowlist_proof`, and `mint_record_params` carries the buyer's mint record
+/// for the phase when it has a wallet limit and the buyer minted in it before.
+/// Configs with hidden metadata mint assets linking their placeholder shared
+/// blob, with empty attributes, until they are revealed. The blob and
+/// attributes are created with `placeholder_proof`.
+pub fn mint_from_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintFromConfig<'info>>,
+    proof: CompressedProof,
+    new_address_params_packed: NewAddressParams,
+    phase: Option<u8>,
+    allowlist_proof: Option<AllowlistProof>,
+    mint_record_params: Option<MintRecordParams>,
+    placeholder_proof: Option<CompressedProof>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mint_config = &mut ctx.accounts.mint_config;
+    let mint_config_key = mint_config.key();
+    let buyer = ctx.accounts.buyer.key();
+    // the leaves are assigned once the reveal started
+    require!(
+        mint_config.reveal_slot.is_none(),
+        ZkNftError::RevealAlreadyStarted
+    );
+    let (price, wallet_limit) =
+        mint_config.mint_terms(now, &buyer, phase, allowlist_proof.as_ref())?;
+    require!(
+        mint_config
+            .max_supply
+            .map_or(true, |max_supply| mint_config.minted < max_supply),
+        ZkNftError::MintSoldOut
+    );
+    let payment_mint = mint_config.payment_mint;
+    let index = mint_config.minted;
+    mint_config.minted = index.checked_add(1).unwrap();
+
+    let group = &mut ctx.accounts.group;
+    if group.max_size > 0 && group.size >= group.max_size {
+        return Err(ZkNftError::GroupMaxSizeExceeded.into());
+    }
+    group.size = group.size.checked_add(1).unwrap();
+
+    pay_mint_price(ctx.accounts, payment_mint, price)?;
+
+    let address_merkle_tree = ctx.remaining_accounts
+        [new_address_params_packed.address_merkle_tree_account_index as usize]
+        .key();
+    require_keys_eq!(
+        address_merkle_tree,
+        ctx.accounts.mint_config.address_merkle_tree,
+        ZkNftError::InvalidAddressMerkleTree
+    );
+    let base_data_seed = MintConfig::asset_seed(&mint_config_key, index)?;
+    let asset_id = derive_address(&address_merkle_tree, &base_data_seed)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+    let asset_data_seed = get_asset_resource_seed(b"asset_data", &asset_id)?;
+    let asset_data_address = derive_address(&address_merkle_tree, &asset_data_seed)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+
+    emit_cpi!(OwnerUpdatedEvent {
+        asset_id: asset_id.into(),
+        owner: buyer,
+    });
+    emit_cpi!(MembershipUpdatedEvent {
+        asset_id: asset_id.into(),
+        group: ctx.accounts.group.key(),
+        group_path: ctx.accounts.group.path.clone(),
+        verified: true,
+    });
+
+    let placeholder = match &ctx.accounts.mint_config.reveal {
+        Some(reveal) => Some((
+            reveal.placeholder.clone(),
+            placeholder_proof.ok_or(ZkNftError::PlaceholderMissing)?,
+        )),
+        None => None,
+    };
+
+    let group = &ctx.accounts.group;
+    let base_data = BaseData {
+        owner: buyer,
+        state: State::Unlocked,
+        delegate: None,
+        delegate_role: DelegateRole::All,
+        delegate_expiry: None,
+        price: None,
+        payment_mint: None,
+    };
+    let asset_data = AssetData {
+        mutable: true,
+        group: Some(group.key()),
+        authority: Some(mint_config_key),
+        has_attributes: placeholder.is_some(),
+        has_blob: placeholder.is_some(),
+        verified: true,
+        royalties: group.royalties.clone(),
+        non_transferable: group.non_transferable,
+        permanent_delegate: group.permanent_delegate,
+        edition: None,
+    };
+
+    let mut new_address_params = vec![
+        new_address_params_packed.pack(base_data_seed),
+        new_address_params_packed.pack(asset_data_seed),
+    ];
+    let mut input_compressed_accounts_with_merkle_context = Vec::new();
+    let mut output_compressed_accounts = vec![
+        get_new_compressed_pda(asset_id, &base_data)?,
+        get_new_compressed_pda(asset_data_address, &asset_data)?,
+    ];
+
+    if let Some(wallet_limit) = wallet_limit {
+        let mint_record_seed = MintRecord::seed(&mint_config_key, &buyer, phase)?;
+        match &mint_record_params {
+            Some(mint_record_params) => {
+                let mint_record = &mint_record_params.data;
+                require!(
+                    mint_record.mint_config == mint_config_key
+                        && mint_record.wallet == buyer
+                        && mint_record.phase == phase,
+                    ZkNftError::MintRecordMismatch
+                );
+                require!(
+                    mint_record.count < wallet_limit,
+                    ZkNftError::WalletLimitReached
+                );
+                require_keys_eq!(
+                    ctx.remaining_accounts
+                        [mint_record_params.address_merkle_tree_account_index as usize]
+                        .key(),
+                    address_merkle_tree,
+                    ZkNftError::InvalidAddressMerkleTree
+                );
+                let mint_record_address = derive_address(&address_merkle_tree, &mint_record_seed)
+                    .map_err(|_| ProgramError::InvalidArgument)?;
+                let (old_mint_record, new_mint_record) = get_old_and_new_compressed_pda(
+                    mint_record_address,
+                    &mint_record_params.input,
+                    mint_record,
+                    &MintRecord {
+                        count: mint_record.count + 1,
+                        ..mint_record.clone()
+                    },
+                )?;
+                input_compressed_accounts_with_merkle_context.push(old_mint_record);
+                output_compressed_accounts.push(new_mint_record);
+            }
+            None => {
+                require!(wallet_limit > 0, ZkNftError::WalletLimitReached);
+                let mint_record_address = derive_address(&address_merkle_tree, &mint_record_seed)
+                    .map_err(|_| ProgramError::InvalidArgument)?;
+                new_address_params.push(new_address_params_packed.pack(mint_record_seed));
+                output_compressed_accounts.push(get_new_compressed_pda(
+                    mint_record_address,
+                    &MintRecord {
+                        mint_config: mint_config_key,
+                        wallet: buyer,
+                        phase,
+                        count: 1,
+                    },
+                )?);
+            }
+        }
+    }
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params,
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context,
+        output_compressed_accounts,
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    let mut inputs: Vec<u8> = Vec::new();
+    InstructionDataInvokeCpi::serialize(&inputs_struct, &mut inputs).unwrap();
+    let cpi_accounts = setup_cpi_accounts(&ctx);
+    invoke_cpi(&ctx, cpi_accounts, inputs, &[&signer_seeds])?;
+
+    if let Some((placeholder, placeholder_proof)) = placeholder {
+        let blob_seed = get_asset_resource_seed(b"blob", &asset_id)?;
+        let blob_address = derive_address(&address_merkle_tree, &blob_seed)
+            .map_err(|_| ProgramError::InvalidArgument)?;
+        let attributes_seed = get_asset_resource_seed(b"attributes", &asset_id)?;
+        let attributes_address = derive_address(&address_merkle_tree, &attributes_seed)
+            .map_err(|_| ProgramError::InvalidArgument)?;
+        let attributes = Attributes {
+            group: Some(ctx.accounts.group.key()),
+            attributes: Vec::new(),
+        };
+
+        let inputs_struct = InstructionDataInvokeCpi {
+            proof: Some(placeholder_proof),
+            new_address_params: vec![
+                new_address_params_packed.pack(blob_seed),
+                new_address_params_packed.pack(attributes_seed),
+            ],
+            relay_fee: None,
+            input_compressed_accounts_with_merkle_context: Vec::new(),
+            output_compressed_accounts: vec![
+                get_new_compressed_pda(blob_address, &placeholder.to_blob())?,
+                get_new_compressed_pda(attributes_address, &attributes)?,
+            ],
+            compress_or_decompress_lamports: None,
+            is_compress: false,
+            signer_seeds: signer_seeds
+                .iter()
+                .map(|x| x.to_vec())
+                .collect::<Vec<Vec<u8>>>(),
+            cpi_context: None,
+        };
+        let mut inputs: Vec<u8> = Vec::new();
+        InstructionDataInvokeCpi::serialize(&inputs_struct, &mut inputs).unwrap();
+        let cpi_accounts = setup_cpi_accounts(&ctx);
+        invoke_cpi(&ctx, cpi_accounts, inputs, &[&signer_seeds])?;
+    }
+
+    Ok(())
+}
+
+fn pay_mint_price<'info>(
+    accounts: &MintFromConfig<'info>,
+    payment_mint: Option<Pubkey>,
+    price: u64,
+) -> Result<()> {
+    let Some(payment_mint) = payment_mint else {
+        return transfer_lamports(
+            &accounts.buyer.to_account_info(),
+            &accounts.treasury.to_account_info(),
+            price,
+        );
+    };
+
+    let mint = accounts
+        .payment_mint
+        .as_ref()
+        .ok_or(ZkNftError::PaymentAccountMissing)?;
+    require_keys_eq!(mint.key(), payment_mint, ZkNftError::InvalidPaymentAccount);
+    let buyer_token_account = accounts
+        .buyer_token_account
+        .as_ref()
+        .ok_or(ZkNftError::PaymentAccountMissing)?;
+    let treasury_token_account = accounts
+        .treasury_token_account
+        .as_ref()
+        .ok_or(ZkNftError::PaymentAccountMissing)?;
+    require_keys_eq!(
+        treasury_token_account.owner,
+        accounts.treasury.key(),
+        ZkNftError::InvalidPaymentAccount
+    );
+    let token_program = accounts
+        .token_program
+        .as_ref()
+        .ok_or(ZkNftError::PaymentAccountMissing)?;
+
+    transfer_tokens(
+        token_program.to_account_info(),
+        buyer_token_account.to_account_info(),
+        mint,
+        treasury_token_account.to_account_info(),
+        accounts.buyer.to_account_info(),
+        price,
+    )
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+pub struct MintFromConfig<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = group,
+        has_one = treasury,
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+    #[account(mut)]
+    pub group: Box<Account<'info, Group>>,
+    /// CHECK: Checked against the mint config.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Required, with the token accounts, when the config has a payment mint.
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub buyer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
@@ -1,15 +1,89 @@
+pub use accept_bid::*;
+pub use approve::*;
+pub use burn::*;
+pub use buy::*;
+pub use cancel_bid::*;
+pub use close_mint_config::*;
+pub use close_recipient_policy::*;
+pub use compress::*;
+pub use compress_from_spl::*;
 pub use create_asset::*;
 pub use create_group::*;
+pub use create_master_edition::*;
+pub use create_mint_config::*;
+pub use create_recipient_policy::*;
+pub use create_shared_blob::*;
+pub use decompress::*;
+pub use delist::*;
 pub use init_blob_upload::*;
+pub use list::*;
+pub use lock::*;
 pub use log_blob::*;
+pub use mint_from_config::*;
+pub use place_bid::*;
+pub use print_edition::*;
+pub use rent_asset::*;
+pub use reveal::*;
+pub use revoke::*;
+pub use set_blob_authority::*;
+pub use set_blob_uploaders::*;
+pub use set_group_collection::*;
+pub use set_group_content_types::*;
+pub use set_group_frozen::*;
+pub use set_group_permanent_delegate::*;
+pub use set_group_royalties::*;
+pub use set_group_transfer_hook::*;
+pub use set_membership_verified::*;
+pub use start_reveal::*;
+pub use swap::*;
 pub use transfer::*;
+pub use unlock::*;
+pub use update_mint_config::*;
+pub use update_recipient_policy::*;
 pub use upload_blob::*;
 // pub use create_blob::*;
 
+mod accept_bid;
+mod approve;
+mod burn;
+mod buy;
+mod cancel_bid;
+mod close_mint_config;
+mod close_recipient_policy;
+mod compress;
+mod compress_from_spl;
 mod create_asset;
 mod create_group;
+mod create_master_edition;
+mod create_mint_config;
+mod create_recipient_policy;
+mod create_shared_blob;
+mod decompress;
+mod delist;
 mod init_blob_upload;
+mod list;
+mod lock;
 mod log_blob;
+mod mint_from_config;
+mod place_bid;
+mod print_edition;
+mod rent_asset;
+mod reveal;
+mod revoke;
+mod set_blob_authority;
+mod set_blob_uploaders;
+mod set_group_collection;
+mod set_group_content_types;
+mod set_group_frozen;
+mod set_group_permanent_delegate;
+mod set_group_royalties;
+mod set_group_transfer_hook;
+mod set_membership_verified;
+mod start_reveal;
+mod swap;
 mod transfer;
+mod unlock;
+mod update_mint_config;
+mod update_recipient_policy;
 mod upload_blob;
 // mod create_blob;
@@ -0,0 +1,104 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{Bid, NewAddressParams};
+use crate::utils::get_compressed_account_data;
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof,
+    invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+    sdk::{address::derive_address, compressed_account::CompressedAccount},
+    NewAddressParamsPacked, OutputCompressedAccountWithPackedContext,
+};
+
+/// Offers `amount` lamports for an asset. The lamports are compressed into the
+/// bid account, which the owner can accept and the bidder can cancel.
+pub fn place_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlaceBid<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    bid_id: u64,
+    amount: u64,
+    new_address_params_packed: NewAddressParams,
+) -> Result<()> {
+    require!(amount > 0, ZkNftError::InvalidPrice);
+
+    let bid = Bid {
+        asset_id,
+        bidder: ctx.accounts.bidder.key(),
+        bid_id,
+        amount,
+    };
+    let bid_seed = Bid::seed(&asset_id, &bid.bidder, bid_id)?;
+    let bid_address = derive_address(
+        &ctx.remaining_accounts
+            [new_address_params_packed.address_merkle_tree_account_index as usize]
+            .key(),
+        &bid_seed,
+    )
+    .map_err(|_| ProgramError::InvalidArgument)?;
+
+    let bid_compressed_pda = OutputCompressedAccountWithPackedContext {
+        compressed_account: CompressedAccount {
+            owner: crate::ID,
+            lamports: amount,
+            address: Some(bid_address),
+            data: Some(get_compressed_account_data(&bid)?),
+        },
+        merkle_tree_index: 0,
+    };
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: vec![NewAddressParamsPacked {
+            seed: bid_seed,
+            address_merkle_tree_account_index: new_address_params_packed
+                .address_merkle_tree_account_index,
+            address_queue_account_index: new_address_params_packed.address_queue_account_index,
+            address_merkle_tree_root_index: new_address_params_packed
+                .address_merkle_tree_root_index,
+        }],
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: Vec::new(),
+        output_compressed_accounts: vec![bid_compressed_pda],
+        // escrow the bid, compressed from the fee payer
+        compress_or_decompress_lamports: Some(amount),
+        is_compress: true,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[derive(Accounts, LightTraits)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub bidder: Signer<'info>,
+    /// CHECK: Checked by the light system program.
+    #[account(mut)]
+    pub sol_pool_pda: UncheckedAccount<'info>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
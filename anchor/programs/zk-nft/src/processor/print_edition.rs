@@ -0,0 +1,238 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{
+    AssetData, AssetDataParams, BaseData, DelegateRole, Edition, EditionPrintedEvent, Group,
+    MembershipUpdatedEvent, NewAddressParams, OwnerUpdatedEvent, PackedInputCompressedPda, State,
+};
+use crate::utils::{
+    derive_asset_resource_address, get_asset_resource_seed, get_compressed_account_data,
+    get_old_and_new_compressed_pda,
+};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof,
+    invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+    sdk::{address::derive_address, compressed_account::CompressedAccount},
+    NewAddressParamsPacked, OutputCompressedAccountWithPackedContext,
+};
+
+/// Mints the next numbered print of a master edition to `recipient`. Signed by
+/// the master's owner, and by the group authority when the master belongs to a
+/// group, as prints join it verified. The print's id is derived from the master id and its
+/// number, and it refers to the master's blob and attributes instead of
+/// copying them.
+pub fn print_edition<'info>(
+    ctx: Context<'_, '_, '_, 'info, PrintEdition<'info>>,
+    proof: CompressedProof,
+    master_id: [u8; 32],
+    master_base_data: BaseData,
+    master_base_data_input: PackedInputCompressedPda,
+    master_asset_data_params: AssetDataParams,
+    new_address_params_packed: NewAddressParams,
+) -> Result<()> {
+    let master_asset_data = &master_asset_data_params.data;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        master_base_data.owner,
+        ZkNftError::InvalidAuthority
+    );
+    require!(master_asset_data.mutable, ZkNftError::AssetNotMutable);
+    let Some(Edition::Master { supply, max_supply }) = master_asset_data.edition else {
+        return Err(ZkNftError::NotMasterEdition.into());
+    };
+    require!(
+        max_supply.map_or(true, |max_supply| supply < max_supply),
+        ZkNftError::EditionSupplyExceeded
+    );
+    let number = supply.checked_add(1).unwrap();
+
+    // prints count towards the size of the master's group
+    if let Some(master_group) = master_asset_data.group {
+        let group = ctx
+            .accounts
+            .group
+            .as_mut()
+            .ok_or(ZkNftError::GroupAccountMissing)?;
+        require_keys_eq!(group.key(), master_group, ZkNftError::GroupMismatch);
+        let group_authority = ctx
+            .accounts
+            .group_authority
+            .as_ref()
+            .ok_or(ZkNftError::GroupAuthorityMissing)?;
+        require_keys_eq!(
+            group.authority,
+            group_authority.key(),
+            ZkNftError::InvalidAuthority
+        );
+        if group.max_size > 0 && group.size >= group.max_size {
+            return Err(ZkNftError::GroupMaxSizeExceeded.into());
+        }
+        group.size = group.size.checked_add(1).unwrap();
+    }
+
+    // master base data is read only, but has to be consumed to prove the owner
+    let (old_master_state, new_master_state) = get_old_and_new_compressed_pda(
+        master_id,
+        &master_base_data_input,
+        &master_base_data,
+        &master_base_data,
+    )?;
+    let master_asset_data_address = derive_asset_resource_address(
+        ctx.remaining_accounts,
+        master_asset_data_params.address_merkle_tree_account_index,
+        b"asset_data",
+        &master_id,
+    )?;
+    let (old_master_asset_data, new_master_asset_data) = get_old_and_new_compressed_pda(
+        master_asset_data_address,
+        &master_asset_data_params.input,
+        master_asset_data,
+        &AssetData {
+            edition: Some(Edition::Master {
+                supply: number,
+                max_supply,
+            }),
+            ..master_asset_data.clone()
+        },
+    )?;
+
+    let address_merkle_tree = ctx.remaining_accounts
+        [new_address_params_packed.address_merkle_tree_account_index as usize]
+        .key();
+    let base_data_seed = get_asset_resource_seed(
+        &[b"edition".as_slice(), &number.to_le_bytes()].concat(),
+        &master_id,
+    )?;
+    let asset_id = derive_address(&address_merkle_tree, &base_data_seed)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+    let asset_data_seed = get_asset_resource_seed(b"asset_data", &asset_id)?;
+    let asset_data_address = derive_address(&address_merkle_tree, &asset_data_seed)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+
+    emit_cpi!(EditionPrintedEvent {
+        asset_id: asset_id.into(),
+        master_id: master_id.into(),
+        number,
+    });
+    emit_cpi!(OwnerUpdatedEvent {
+        asset_id: asset_id.into(),
+        owner: ctx.accounts.recipient.key(),
+    });
+    if let (Some(_), Some(group)) = (master_asset_data.group, &ctx.accounts.group) {
+        emit_cpi!(MembershipUpdatedEvent {
+            asset_id: asset_id.into(),
+            group: group.key(),
+            group_path: group.path.clone(),
+            verified: master_asset_data.verified,
+        });
+    }
+
+    let base_data = BaseData {
+        owner: ctx.accounts.recipient.key(),
+        state: State::Unlocked,
+        delegate: None,
+        delegate_role: DelegateRole::All,
+        delegate_expiry: None,
+        price: None,
+        payment_mint: None,
+    };
+    let asset_data = AssetData {
+        edition: Some(Edition::Print {
+            parent: master_id,
+            number,
+        }),
+        ..master_asset_data.clone()
+    };
+
+    let new_address_params = [base_data_seed, asset_data_seed]
+        .into_iter()
+        .map(|seed| NewAddressParamsPacked {
+            seed,
+            address_merkle_tree_account_index: new_address_params_packed
+                .address_merkle_tree_account_index,
+            address_queue_account_index: new_address_params_packed.address_queue_account_index,
+            address_merkle_tree_root_index: new_address_params_packed
+                .address_merkle_tree_root_index,
+        })
+        .collect();
+    let base_data_compressed_pda = OutputCompressedAccountWithPackedContext {
+        compressed_account: CompressedAccount {
+            owner: crate::ID,
+            lamports: 0,
+            address: Some(asset_id),
+            data: Some(get_compressed_account_data(&base_data)?),
+        },
+        merkle_tree_index: 0,
+    };
+    let asset_data_compressed_pda = OutputCompressedAccountWithPackedContext {
+        compressed_account: CompressedAccount {
+            owner: crate::ID,
+            lamports: 0,
+            address: Some(asset_data_address),
+            data: Some(get_compressed_account_data(&asset_data)?),
+        },
+        merkle_tree_index: 0,
+    };
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params,
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: vec![
+            old_master_state,
+            old_master_asset_data,
+        ],
+        output_compressed_accounts: vec![
+            new_master_state,
+            new_master_asset_data,
+            base_data_compressed_pda,
+            asset_data_compressed_pda,
+        ],
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+pub struct PrintEdition<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    /// Owner of the master edition.
+    pub authority: Signer<'info>,
+    /// CHECK: This can be any valid public key.
+    pub recipient: UncheckedAccount<'info>,
+    /// Required when the master belongs to a group.
+    #[account(mut)]
+    pub group: Option<Box<Account<'info, Group>>>,
+    /// Required when the master belongs to a group.
+    pub group_authority: Option<Signer<'info>>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
@@ -0,0 +1,150 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{AssetDataParams, Attributes, MintConfig, RevealParams};
+use crate::utils::{
+    derive_asset_resource_address, get_old_and_new_compressed_pda,
+    get_unchanged_asset_data_compressed_pda, validate_content_type,
+};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram, sdk::address::derive_address,
+};
+
+/// Replaces the placeholder blob and attributes of an asset minted from the
+/// config with the metadata committed for its leaf, its mint index shifted by
+/// the config's reveal offset. Anyone can reveal, since only the committed
+/// metadata passes the proof.
+pub fn reveal<'info>(
+    ctx: Context<'_, '_, '_, 'info, Reveal<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    asset_data_params: AssetDataParams,
+    reveal_params: RevealParams,
+) -> Result<()> {
+    let mint_config = &ctx.accounts.mint_config;
+    let reveal = mint_config
+        .reveal
+        .as_ref()
+        .ok_or(ZkNftError::AssetNotRevealable)?;
+    let asset_data = &asset_data_params.data;
+    let address_merkle_tree_account_index = asset_data_params.address_merkle_tree_account_index;
+
+    // the asset was minted from the config at the revealed index
+    require!(
+        asset_data.authority == Some(mint_config.key()),
+        ZkNftError::AssetNotRevealable
+    );
+    let asset_address = derive_address(
+        &ctx.remaining_accounts[address_merkle_tree_account_index as usize].key(),
+        &MintConfig::asset_seed(&mint_config.key(), reveal_params.index)?,
+    )
+    .map_err(|_| ProgramError::InvalidArgument)?;
+    require!(asset_address == asset_id, ZkNftError::InvalidRevealProof);
+
+    // and is still unrevealed
+    require!(
+        reveal
+            .placeholder
+            .is_linked_by(&reveal_params.placeholder_blob),
+        ZkNftError::AssetNotRevealable
+    );
+
+    require!(
+        reveal.verify(
+            mint_config.reveal_leaf_index(reveal_params.index)?,
+            &reveal_params.blob,
+            &reveal_params.attributes,
+            &reveal_params.proof,
+        ),
+        ZkNftError::InvalidRevealProof
+    );
+    validate_content_type(&reveal_params.blob.content_type)?;
+
+    let blob_address = derive_asset_resource_address(
+        ctx.remaining_accounts,
+        address_merkle_tree_account_index,
+        b"blob",
+        &asset_id,
+    )?;
+    let (old_blob, new_blob) = get_old_and_new_compressed_pda(
+        blob_address,
+        &reveal_params.placeholder_blob_input,
+        &reveal_params.placeholder_blob,
+        &reveal_params.blob,
+    )?;
+    let attributes_address = derive_asset_resource_address(
+        ctx.remaining_accounts,
+        address_merkle_tree_account_index,
+        b"attributes",
+        &asset_id,
+    )?;
+    let (old_attributes, new_attributes) = get_old_and_new_compressed_pda(
+        attributes_address,
+        &reveal_params.placeholder_attributes_input,
+        &reveal_params.placeholder_attributes,
+        &Attributes {
+            group: asset_data.group,
+            attributes: reveal_params.attributes.clone(),
+        },
+    )?;
+
+    // asset data is read only, but has to be consumed to prove the authority
+    let (old_asset_data, new_asset_data) = get_unchanged_asset_data_compressed_pda(
+        ctx.remaining_accounts,
+        &asset_id,
+        &asset_data_params,
+    )?;
+
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.revealed = mint_config.revealed.checked_add(1).unwrap();
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: vec![
+            old_asset_data,
+            old_blob,
+            old_attributes,
+        ],
+        output_compressed_accounts: vec![new_asset_data, new_blob, new_attributes],
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[derive(Accounts, LightTraits)]
+pub struct Reveal<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
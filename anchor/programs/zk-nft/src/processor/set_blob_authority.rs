@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_BLOB_UPLOADERS;
+use crate::UploadedBlob;
+
+/// Hands the buffer over to `new_authority`, revoking all additional uploaders.
+pub fn set_blob_authority(ctx: Context<SetBlobAuthority>, new_authority: Pubkey) -> Result<()> {
+    let uploaded_blob = &mut ctx.accounts.uploaded_blob;
+    uploaded_blob.authority = new_authority;
+    uploaded_blob.uploaders = [Pubkey::default(); MAX_BLOB_UPLOADERS];
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBlobAuthority<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub uploaded_blob: Account<'info, UploadedBlob>,
+    pub authority: Signer<'info>,
+}
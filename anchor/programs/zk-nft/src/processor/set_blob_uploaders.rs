@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_BLOB_UPLOADERS;
+use crate::errors::ZkNftError;
+use crate::UploadedBlob;
+
+pub fn set_blob_uploaders(ctx: Context<SetBlobUploaders>, uploaders: Vec<Pubkey>) -> Result<()> {
+    require!(
+        uploaders.len() <= MAX_BLOB_UPLOADERS,
+        ZkNftError::TooManyUploaders
+    );
+
+    let uploaded_blob = &mut ctx.accounts.uploaded_blob;
+    uploaded_blob.uploaders = [Pubkey::default(); MAX_BLOB_UPLOADERS];
+    uploaded_blob.uploaders[..uploaders.len()].copy_from_slice(&uploaders);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBlobUploaders<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub uploaded_blob: Account<'info, UploadedBlob>,
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+
+use crate::errors::ZkNftError;
+use crate::state::Group;
+
+/// Maps a Metaplex collection onto the group, for `compress_from_spl` imports.
+/// The collection's update authority has to sign to hand its NFTs to the group.
+pub fn set_group_collection<'info>(
+    ctx: Context<SetGroupCollection<'info>>,
+    collection: Option<Pubkey>,
+) -> Result<()> {
+    if let Some(collection) = collection {
+        let collection_metadata = ctx
+            .accounts
+            .collection_metadata
+            .as_ref()
+            .ok_or(ZkNftError::InvalidMetadata)?;
+        require_keys_eq!(
+            collection_metadata.mint,
+            collection,
+            ZkNftError::InvalidMetadata
+        );
+        let collection_authority = ctx
+            .accounts
+            .collection_authority
+            .as_ref()
+            .ok_or(ZkNftError::InvalidAuthority)?;
+        require_keys_eq!(
+            collection_authority.key(),
+            collection_metadata.update_authority,
+            ZkNftError::InvalidAuthority
+        );
+    }
+    ctx.accounts.group.collection = collection;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGroupCollection<'info> {
+    pub group_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+    /// Update authority of the collection, required when setting one.
+    pub collection_authority: Option<Signer<'info>>,
+    /// Required when setting a collection.
+    pub collection_metadata: Option<Box<Account<'info, MetadataAccount>>>,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_GROUP_CONTENT_TYPES;
+use crate::errors::ZkNftError;
+use crate::state::Group;
+use crate::utils::{content_type_essence, validate_content_type};
+
+pub fn set_group_content_types<'info>(
+    ctx: Context<SetGroupContentTypes<'info>>,
+    content_types: Vec<String>,
+) -> Result<()> {
+    require!(
+        content_types.len() <= MAX_GROUP_CONTENT_TYPES,
+        ZkNftError::TooManyContentTypes
+    );
+    for content_type in &content_types {
+        validate_content_type(content_type)?;
+    }
+
+    ctx.accounts.group.allowed_content_types = content_types
+        .iter()
+        .map(|content_type| content_type_essence(content_type))
+        .collect();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGroupContentTypes<'info> {
+    pub group_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::{Group, GroupFrozenEvent};
+
+pub fn set_group_frozen<'info>(ctx: Context<SetGroupFrozen<'info>>, frozen: bool) -> Result<()> {
+    ctx.accounts.group.frozen = frozen;
+
+    emit_cpi!(GroupFrozenEvent {
+        group: ctx.accounts.group.key(),
+        group_path: ctx.accounts.group.path.clone(),
+        frozen,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGroupFrozen<'info> {
+    pub group_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::Group;
+
+/// Sets the permanent delegate given to assets minted into the group from now on.
+pub fn set_group_permanent_delegate<'info>(
+    ctx: Context<SetGroupPermanentDelegate<'info>>,
+    permanent_delegate: Option<Pubkey>,
+) -> Result<()> {
+    ctx.accounts.group.permanent_delegate = permanent_delegate;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGroupPermanentDelegate<'info> {
+    pub group_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::{Group, Royalties};
+
+pub fn set_group_royalties<'info>(
+    ctx: Context<SetGroupRoyalties<'info>>,
+    royalties: Option<Royalties>,
+) -> Result<()> {
+    if let Some(royalties) = &royalties {
+        royalties.validate()?;
+    }
+    ctx.accounts.group.royalties = royalties;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGroupRoyalties<'info> {
+    pub group_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+}
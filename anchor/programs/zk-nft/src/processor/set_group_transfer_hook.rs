@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::Group;
+
+pub fn set_group_transfer_hook<'info>(
+    ctx: Context<SetGroupTransferHook<'info>>,
+    transfer_hook_program: Option<Pubkey>,
+) -> Result<()> {
+    ctx.accounts.group.transfer_hook_program = transfer_hook_program;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGroupTransferHook<'info> {
+    pub group_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+}
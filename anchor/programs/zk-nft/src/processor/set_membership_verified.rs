@@ -0,0 +1,96 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{AssetData, AssetDataParams, Group, MembershipUpdatedEvent};
+use crate::utils::{derive_asset_resource_address, get_old_and_new_compressed_pda};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+/// Verifies or unverifies an asset as a member of the group it was minted into.
+pub fn set_membership_verified<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetMembershipVerified<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    asset_data_params: AssetDataParams,
+    verified: bool,
+) -> Result<()> {
+    let asset_data = &asset_data_params.data;
+    require!(
+        asset_data.group == Some(ctx.accounts.group.key()),
+        ZkNftError::GroupMismatch
+    );
+
+    emit_cpi!(MembershipUpdatedEvent {
+        asset_id: asset_id.into(),
+        group: ctx.accounts.group.key(),
+        group_path: ctx.accounts.group.path.clone(),
+        verified,
+    });
+
+    let asset_data_address = derive_asset_resource_address(
+        ctx.remaining_accounts,
+        asset_data_params.address_merkle_tree_account_index,
+        b"asset_data",
+        &asset_id,
+    )?;
+    let (old_state, new_state) = get_old_and_new_compressed_pda(
+        asset_data_address,
+        &asset_data_params.input,
+        asset_data,
+        &AssetData {
+            verified,
+            ..asset_data.clone()
+        },
+    )?;
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context: vec![old_state],
+        output_compressed_accounts: vec![new_state],
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+pub struct SetMembershipVerified<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    pub group_authority: Signer<'info>,
+    #[account(
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
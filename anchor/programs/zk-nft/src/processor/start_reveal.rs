@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+
+use crate::constants::REVEAL_SLOT_DELAY;
+use crate::errors::ZkNftError;
+use crate::state::MintConfig;
+
+/// Draws the offset shifting the config's assets onto the committed metadata
+/// leaves. Anyone can start the reveal once minting is closed, which fixes a
+/// future target slot, and call it again once the slot passed to draw the
+/// offset from its hash. Nobody knows the hash while they can still mint, and
+/// nobody can pick a convenient one. A target slot no longer held by the slot
+/// hashes sysvar is moved forward again.
+pub fn start_reveal<'info>(ctx: Context<StartReveal<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let mint_config = &mut ctx.accounts.mint_config;
+    require!(mint_config.reveal.is_some(), ZkNftError::AssetNotRevealable);
+    require!(
+        mint_config.reveal_offset.is_none(),
+        ZkNftError::RevealAlreadyStarted
+    );
+    let Some(reveal_slot) = mint_config.reveal_slot else {
+        require!(
+            mint_config.is_closed(clock.unix_timestamp),
+            ZkNftError::RevealLocked
+        );
+        mint_config.reveal_slot = Some(clock.slot + REVEAL_SLOT_DELAY);
+        return Ok(());
+    };
+
+    // entries are (slot, hash) pairs after the length prefix, most recent
+    // first, the offset comes from the first block at or after the target slot
+    let slot_hashes = ctx.accounts.slot_hashes.try_borrow_data()?;
+    let len = u64::from_le_bytes(slot_hashes[..8].try_into().unwrap()) as usize;
+    let mut reveal_hash = None;
+    let mut reached_target = false;
+    for entry in slot_hashes[8..].chunks_exact(40).take(len) {
+        if u64::from_le_bytes(entry[..8].try_into().unwrap()) < reveal_slot {
+            reached_target = true;
+            break;
+        }
+        reveal_hash = Some(&entry[8..16]);
+    }
+    match (reveal_hash, reached_target) {
+        (Some(reveal_hash), true) => {
+            mint_config.reveal_offset = Some(u64::from_le_bytes(reveal_hash.try_into().unwrap()));
+        }
+        (None, true) => return Err(ZkNftError::RevealSlotNotReached.into()),
+        (_, false) => mint_config.reveal_slot = Some(clock.slot + REVEAL_SLOT_DELAY),
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct StartReveal<'info> {
+    #[account(mut)]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+    /// CHECK: Read by hand, the sysvar is too large to deserialize.
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
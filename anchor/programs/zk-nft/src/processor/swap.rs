@@ -0,0 +1,281 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{
+    AssetDataParams, BaseData, DelegateRole, Group, OwnerUpdatedEvent, PackedInputCompressedPda,
+    RecipientPolicy, RecipientProof, State,
+};
+use crate::utils::{
+    check_group_transfer_restrictions, get_old_and_new_compressed_pda,
+    get_unchanged_asset_data_compressed_pda, invoke_transfer_hook, transfer_lamports,
+    transfer_tokens,
+};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+/// Exchanges the owners of two assets in a single proof, optionally with a
+/// lamport or token payment from one owner to the other.
+pub fn swap<'info>(
+    ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+    proof: CompressedProof,
+    leg_a: SwapLeg,
+    leg_b: SwapLeg,
+    payment: Option<SwapPayment>,
+) -> Result<()> {
+    let owner_a = ctx.accounts.owner_a.key();
+    let owner_b = ctx.accounts.owner_b.key();
+    require_keys_neq!(owner_a, owner_b, ZkNftError::SwapSameOwner);
+    require!(leg_a.asset_id != leg_b.asset_id, ZkNftError::SwapSameAsset);
+
+    // asset a goes to owner b and asset b to owner a
+    let transfer_hook_program_a = check_swap_leg(
+        &leg_a,
+        owner_a,
+        owner_b,
+        ctx.accounts.group_a.as_deref(),
+        ctx.accounts.recipient_policy_a.as_deref(),
+    )?;
+    let transfer_hook_program_b = check_swap_leg(
+        &leg_b,
+        owner_b,
+        owner_a,
+        ctx.accounts.group_b.as_deref(),
+        ctx.accounts.recipient_policy_b.as_deref(),
+    )?;
+
+    if let Some(payment) = payment {
+        pay_swap(&ctx, &payment)?;
+    }
+
+    for (leg, transfer_hook_program, group, authority, recipient) in [
+        (
+            &leg_a,
+            transfer_hook_program_a,
+            &ctx.accounts.group_a,
+            &ctx.accounts.owner_a,
+            &ctx.accounts.owner_b,
+        ),
+        (
+            &leg_b,
+            transfer_hook_program_b,
+            &ctx.accounts.group_b,
+            &ctx.accounts.owner_b,
+            &ctx.accounts.owner_a,
+        ),
+    ] {
+        if let Some(transfer_hook_program) = transfer_hook_program {
+            invoke_transfer_hook(
+                transfer_hook_program,
+                group.as_ref().unwrap().to_account_info(),
+                authority.to_account_info(),
+                recipient.to_account_info(),
+                ctx.remaining_accounts,
+                leg.asset_id,
+                authority.key(),
+            )?;
+        }
+    }
+
+    emit_cpi!(OwnerUpdatedEvent {
+        asset_id: leg_a.asset_id.into(),
+        owner: owner_b,
+    });
+    emit_cpi!(OwnerUpdatedEvent {
+        asset_id: leg_b.asset_id.into(),
+        owner: owner_a,
+    });
+
+    let mut input_compressed_accounts_with_merkle_context = Vec::new();
+    let mut output_compressed_accounts = Vec::new();
+    for (leg, new_owner) in [(&leg_a, owner_b), (&leg_b, owner_a)] {
+        let (old_state, new_state) = get_old_and_new_compressed_pda(
+            leg.asset_id,
+            &leg.base_data_input,
+            &leg.base_data,
+            // the new owner starts without any of the previous owner's delegations or listing
+            &BaseData {
+                owner: new_owner,
+                delegate: None,
+                delegate_role: DelegateRole::All,
+                state: State::Unlocked,
+                delegate_expiry: None,
+                price: None,
+                payment_mint: None,
+            },
+        )?;
+        let (old_asset_data, new_asset_data) = get_unchanged_asset_data_compressed_pda(
+            ctx.remaining_accounts,
+            &leg.asset_id,
+            &leg.asset_data_params,
+        )?;
+        input_compressed_accounts_with_merkle_context.push(old_state);
+        input_compressed_accounts_with_merkle_context.push(old_asset_data);
+        output_compressed_accounts.push(new_state);
+        output_compressed_accounts.push(new_asset_data);
+    }
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context,
+        output_compressed_accounts,
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+fn check_swap_leg<'info>(
+    leg: &SwapLeg,
+    owner: Pubkey,
+    recipient: Pubkey,
+    group: Option<&Account<'info, Group>>,
+    recipient_policy: Option<&Account<'info, RecipientPolicy>>,
+) -> Result<Option<Pubkey>> {
+    let now = Clock::get()?.unix_timestamp;
+    let asset_data = &leg.asset_data_params.data;
+
+    require_keys_eq!(leg.base_data.owner, owner, ZkNftError::InvalidAuthority);
+    require!(
+        leg.base_data.effective_state(now) == State::Unlocked,
+        ZkNftError::AssetIsLocked
+    );
+    require!(
+        !asset_data.non_transferable,
+        ZkNftError::AssetNonTransferable
+    );
+    check_group_transfer_restrictions(
+        asset_data,
+        group,
+        recipient_policy,
+        &recipient,
+        leg.recipient_proof.as_ref(),
+    )
+}
+
+fn pay_swap<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Swap<'info>>,
+    payment: &SwapPayment,
+) -> Result<()> {
+    let (from, to) = if payment.from_owner_a {
+        (&ctx.accounts.owner_a, &ctx.accounts.owner_b)
+    } else {
+        (&ctx.accounts.owner_b, &ctx.accounts.owner_a)
+    };
+
+    match &ctx.accounts.payment_mint {
+        Some(payment_mint) => {
+            let (from_token_account, to_token_account) = if payment.from_owner_a {
+                (
+                    &ctx.accounts.owner_a_token_account,
+                    &ctx.accounts.owner_b_token_account,
+                )
+            } else {
+                (
+                    &ctx.accounts.owner_b_token_account,
+                    &ctx.accounts.owner_a_token_account,
+                )
+            };
+            let from_token_account = from_token_account
+                .as_ref()
+                .ok_or(ZkNftError::PaymentAccountMissing)?;
+            let to_token_account = to_token_account
+                .as_ref()
+                .ok_or(ZkNftError::PaymentAccountMissing)?;
+            require_keys_eq!(
+                to_token_account.owner,
+                to.key(),
+                ZkNftError::InvalidPaymentAccount
+            );
+            let token_program = ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .ok_or(ZkNftError::PaymentAccountMissing)?;
+
+            transfer_tokens(
+                token_program.to_account_info(),
+                from_token_account.to_account_info(),
+                payment_mint,
+                to_token_account.to_account_info(),
+                from.to_account_info(),
+                payment.amount,
+            )
+        }
+        None => transfer_lamports(
+            &from.to_account_info(),
+            &to.to_account_info(),
+            payment.amount,
+        ),
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SwapLeg {
+    pub asset_id: [u8; 32],
+    pub base_data: BaseData,
+    pub base_data_input: PackedInputCompressedPda,
+    pub asset_data_params: AssetDataParams,
+    /// Proof for the other owner, if the asset's group has a recipient policy.
+    pub recipient_proof: Option<RecipientProof>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SwapPayment {
+    /// Paid by owner a to owner b when set, by owner b to owner a otherwise.
+    pub from_owner_a: bool,
+    /// In lamports, or in `payment_mint` tokens when provided.
+    pub amount: u64,
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+pub struct Swap<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub owner_a: Signer<'info>,
+    #[account(mut)]
+    pub owner_b: Signer<'info>,
+    pub group_a: Option<Box<Account<'info, Group>>>,
+    pub group_b: Option<Box<Account<'info, Group>>>,
+    pub recipient_policy_a: Option<Box<Account<'info, RecipientPolicy>>>,
+    pub recipient_policy_b: Option<Box<Account<'info, RecipientPolicy>>>,
+
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub owner_a_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub owner_b_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
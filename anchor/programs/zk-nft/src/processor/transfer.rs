@@ -1,7 +1,14 @@
 use crate::errors::ZkNftError;
-use crate::state::BaseData;
+use crate::state::{
+    AssetDataParams, BaseData, DelegateRole, Group, PermanentDelegateAction,
+    PermanentDelegateEvent, RecipientPolicy, RecipientProof,
+};
+use crate::utils::{
+    check_group_transfer_restrictions, get_group_transfer_hook,
+    get_unchanged_asset_data_compressed_pda, invoke_transfer_hook, pay_royalties,
+};
 use crate::{constants::CPI_AUTHORITY_SEED, state::State};
-use crate::{DelegateRole, OwnerUpdatedEvent, PackedInputCompressedPda};
+use crate::{OwnerUpdatedEvent, PackedInputCompressedPda};
 use account_compression::{program::AccountCompression, RegisteredProgram};
 use anchor_lang::{prelude::*, Discriminator};
 use light_hasher::{DataHasher, Poseidon};
@@ -24,20 +31,79 @@ pub fn transfer<'info>(
     asset_id: [u8; 32],
     base_data: BaseData,
     base_data_input: PackedInputCompressedPda,
+    asset_data_params: AssetDataParams,
+    sale_price: Option<u64>,
+    recipient_proof: Option<RecipientProof>,
 ) -> Result<()> {
+    // decompressed assets move as tokens until compressed again
     require!(
-        base_data.state == State::Unlocked,
-        ZkNftError::AssetIsLocked
+        base_data.state != State::Decompressed,
+        ZkNftError::AssetDecompressed
     );
-    if ctx.accounts.authority.key() != base_data.owner {
+    let asset_data = &asset_data_params.data;
+
+    let transfer_hook_program;
+    if asset_data.permanent_delegate == Some(ctx.accounts.authority.key()) {
+        // clawbacks must work while the group is frozen or the owner is denied
+        transfer_hook_program = get_group_transfer_hook(asset_data, ctx.accounts.group.as_deref())?;
+        // a seizure by the permanent delegate is not a sale and pays no royalties
+        emit_cpi!(PermanentDelegateEvent {
+            asset_id: asset_id.into(),
+            permanent_delegate: ctx.accounts.authority.key(),
+            action: PermanentDelegateAction::Transfer,
+        });
+    } else {
+        transfer_hook_program = check_group_transfer_restrictions(
+            asset_data,
+            ctx.accounts.group.as_deref(),
+            ctx.accounts.recipient_policy.as_deref(),
+            ctx.accounts.recipient.key,
+            recipient_proof.as_ref(),
+        )?;
+        let now = Clock::get()?.unix_timestamp;
         require!(
-            Some(ctx.accounts.authority.key()) == base_data.delegate
-                && (base_data.delegate_role == DelegateRole::All
-                    || base_data.delegate_role == DelegateRole::Transfer
-                    || base_data.delegate_role == DelegateRole::TransferAndLock
-                    || base_data.delegate_role == DelegateRole::TransferAndBurn),
-            ZkNftError::InvalidAuthority
+            base_data.effective_state(now) == State::Unlocked,
+            ZkNftError::AssetIsLocked
         );
+        if asset_data.non_transferable {
+            // only the asset authority can recover a soulbound asset
+            require!(
+                asset_data.authority == Some(ctx.accounts.authority.key()),
+                ZkNftError::AssetNonTransferable
+            );
+        } else if ctx.accounts.authority.key() != base_data.owner {
+            require!(
+                Some(ctx.accounts.authority.key()) == base_data.active_delegate(now)
+                    && base_data.delegate_role.can_transfer(),
+                ZkNftError::InvalidAuthority
+            );
+        }
+
+        // enforced royalties are owed on sales, gifts declare no sale price
+        let enforced_royalties = asset_data
+            .royalties
+            .as_ref()
+            .filter(|royalties| royalties.enforced);
+        if let (Some(royalties), Some(sale_price)) = (enforced_royalties, sale_price) {
+            pay_royalties(
+                &ctx.accounts.payer.to_account_info(),
+                ctx.remaining_accounts,
+                royalties,
+                sale_price,
+            )?;
+        }
+    }
+
+    if let Some(transfer_hook_program) = transfer_hook_program {
+        invoke_transfer_hook(
+            transfer_hook_program,
+            ctx.accounts.group.as_ref().unwrap().to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.recipient.to_account_info(),
+            ctx.remaining_accounts,
+            asset_id,
+            base_data.owner,
+        )?;
     }
 
     emit_cpi!(OwnerUpdatedEvent {
@@ -49,14 +115,29 @@ pub fn transfer<'info>(
         asset_id,
         &base_data_input,
         &base_data,
+        // delegations are granted by the previous owner and end with the transfer
         BaseData {
             owner: ctx.accounts.recipient.key(),
-            state: base_data.state,
-            delegate: base_data.delegate,
-            delegate_role: base_data.delegate_role,
+            state: if base_data.state == State::LockedByPermanentDelegate {
+                State::LockedByPermanentDelegate
+            } else {
+                State::Unlocked
+            },
+            delegate: None,
+            delegate_role: DelegateRole::All,
+            delegate_expiry: None,
+            price: None,
+            payment_mint: None,
         },
     )?;
 
+    // consumed unchanged, proving the royalties above are the asset's own
+    let (old_asset_data, new_asset_data) = get_unchanged_asset_data_compressed_pda(
+        ctx.remaining_accounts,
+        &asset_id,
+        &asset_data_params,
+    )?;
+
     // make light system program cpi
     let bump_seed = &[254];
     let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
@@ -64,8 +145,8 @@ pub fn transfer<'info>(
         proof: Some(proof),
         new_address_params: Vec::new(),
         relay_fee: None,
-        input_compressed_accounts_with_merkle_context: vec![old_state],
-        output_compressed_accounts: vec![new_state],
+        input_compressed_accounts_with_merkle_context: vec![old_state, old_asset_data],
+        output_compressed_accounts: vec![new_state, new_asset_data],
         compress_or_decompress_lamports: None,
         is_compress: false,
         signer_seeds: signer_seeds
@@ -138,6 +219,10 @@ pub struct Transfer<'info> {
     pub authority: Signer<'info>,
     /// CHECK: This can be any valid public key.
     pub recipient: UncheckedAccount<'info>,
+    /// Required when the asset belongs to a group.
+    pub group: Option<Box<Account<'info, Group>>>,
+    /// Required when the group has a recipient policy.
+    pub recipient_policy: Option<Box<Account<'info, RecipientPolicy>>>,
 
     #[authority]
     #[account(
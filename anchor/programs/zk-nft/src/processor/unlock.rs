@@ -0,0 +1,144 @@
+use crate::constants::CPI_AUTHORITY_SEED;
+use crate::errors::ZkNftError;
+use crate::state::{
+    AssetDataParams, BaseData, DelegateRole, PackedInputCompressedPda, PermanentDelegateAction,
+    PermanentDelegateEvent, State,
+};
+use crate::utils::{get_old_and_new_compressed_pda, get_unchanged_asset_data_compressed_pda};
+use account_compression::{program::AccountCompression, RegisteredProgram};
+use anchor_lang::prelude::*;
+use light_sdk::traits::*;
+use light_sdk::verify::verify;
+use light_sdk::{light_accounts, LightTraits};
+use light_system_program::InstructionDataInvokeCpi;
+use light_system_program::{
+    invoke::processor::CompressedProof, invoke_cpi::account::CpiContextAccount,
+    program::LightSystemProgram,
+};
+
+pub fn unlock<'info>(
+    ctx: Context<'_, '_, '_, 'info, Unlock<'info>>,
+    proof: CompressedProof,
+    asset_id: [u8; 32],
+    base_data: BaseData,
+    base_data_input: PackedInputCompressedPda,
+    asset_data_params: Option<AssetDataParams>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // only compress takes an asset out of the decompressed state
+    require!(
+        base_data.state != State::Decompressed,
+        ZkNftError::AssetDecompressed
+    );
+    let authority = ctx.accounts.authority.key();
+    if asset_data_params
+        .as_ref()
+        .is_some_and(|params| params.data.permanent_delegate == Some(authority))
+    {
+        require!(
+            base_data.state != State::Unlocked,
+            ZkNftError::AssetNotLocked
+        );
+        emit_cpi!(PermanentDelegateEvent {
+            asset_id: asset_id.into(),
+            permanent_delegate: authority,
+            action: PermanentDelegateAction::Unlock,
+        });
+    } else {
+        match base_data.state {
+            State::Unlocked => return Err(ZkNftError::AssetNotLocked.into()),
+            State::LockedByAuthority => {
+                require_keys_eq!(authority, base_data.owner, ZkNftError::InvalidAuthority)
+            }
+            // the delegate can unlock early, the owner once the delegation expired
+            State::LockedByDelegate | State::Rented => require!(
+                Some(authority) == base_data.active_delegate(now)
+                    || (authority == base_data.owner && base_data.is_delegate_expired(now)),
+                ZkNftError::InvalidAuthority
+            ),
+            State::LockedByPermanentDelegate => return Err(ZkNftError::InvalidAuthority.into()),
+            State::Decompressed => return Err(ZkNftError::AssetDecompressed.into()),
+        }
+    }
+
+    let (old_state, new_state) = get_old_and_new_compressed_pda(
+        asset_id,
+        &base_data_input,
+        &base_data,
+        &unlocked_base_data(&base_data, now),
+    )?;
+    let mut input_compressed_accounts_with_merkle_context = vec![old_state];
+    let mut output_compressed_accounts = vec![new_state];
+    if let Some(asset_data_params) = &asset_data_params {
+        let (old_asset_data, new_asset_data) = get_unchanged_asset_data_compressed_pda(
+            ctx.remaining_accounts,
+            &asset_id,
+            asset_data_params,
+        )?;
+        input_compressed_accounts_with_merkle_context.push(old_asset_data);
+        output_compressed_accounts.push(new_asset_data);
+    }
+
+    // make light system program cpi
+    let bump_seed = &[254];
+    let signer_seeds: [&[u8]; 2] = [CPI_AUTHORITY_SEED.as_bytes(), bump_seed];
+    let inputs_struct = InstructionDataInvokeCpi {
+        proof: Some(proof),
+        new_address_params: Vec::new(),
+        relay_fee: None,
+        input_compressed_accounts_with_merkle_context,
+        output_compressed_accounts,
+        compress_or_decompress_lamports: None,
+        is_compress: false,
+        signer_seeds: signer_seeds
+            .iter()
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<u8>>>(),
+        cpi_context: None,
+    };
+    verify(ctx, &inputs_struct, &[&signer_seeds])?;
+
+    Ok(())
+}
+
+/// Unlocks the asset and drops any listing. Ending a rental or an expired
+/// delegation also revokes the delegate.
+fn unlocked_base_data(base_data: &BaseData, now: i64) -> BaseData {
+    if base_data.state == State::Rented || base_data.is_delegate_expired(now) {
+        BaseData {
+            state: State::Unlocked,
+            delegate: None,
+            delegate_role: DelegateRole::All,
+            delegate_expiry: None,
+            price: None,
+            payment_mint: None,
+            ..base_data.clone()
+        }
+    } else {
+        BaseData {
+            state: State::Unlocked,
+            price: None,
+            payment_mint: None,
+            ..base_data.clone()
+        }
+    }
+}
+
+#[light_accounts]
+#[event_cpi]
+#[derive(Accounts, LightTraits)]
+pub struct Unlock<'info> {
+    #[account(mut)]
+    #[fee_payer]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    #[authority]
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED.as_bytes()],
+        bump = 254,
+    )]
+    pub cpi_authority_pda: SystemAccount<'info>,
+    #[self_program]
+    pub self_program: Program<'info, crate::program::ZkNft>,
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::{Group, MintConfig, MintConfigParams};
+
+pub fn update_mint_config<'info>(
+    ctx: Context<UpdateMintConfig<'info>>,
+    params: MintConfigParams,
+) -> Result<()> {
+    ctx.accounts.mint_config.set_params(params)
+}
+
+#[derive(Accounts)]
+pub struct UpdateMintConfig<'info> {
+    pub group_authority: Signer<'info>,
+    #[account(
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+    #[account(
+        mut,
+        has_one = group,
+    )]
+    pub mint_config: Box<Account<'info, MintConfig>>,
+}
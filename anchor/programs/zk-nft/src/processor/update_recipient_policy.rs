@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::{Group, RecipientPolicy, RecipientPolicyMode};
+
+pub fn update_recipient_policy<'info>(
+    ctx: Context<UpdateRecipientPolicy<'info>>,
+    mode: RecipientPolicyMode,
+    root: [u8; 32],
+) -> Result<()> {
+    let recipient_policy = &mut ctx.accounts.recipient_policy;
+    recipient_policy.mode = mode;
+    recipient_policy.root = root;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateRecipientPolicy<'info> {
+    pub group_authority: Signer<'info>,
+    #[account(
+        constraint = group.authority == group_authority.key() @ ZkNftError::InvalidAuthority,
+    )]
+    pub group: Box<Account<'info, Group>>,
+    #[account(
+        mut,
+        has_one = group,
+    )]
+    pub recipient_policy: Box<Account<'info, RecipientPolicy>>,
+}
@@ -1,8 +1,14 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ZkNftError;
 use crate::{constants::UPLOADED_BLOB_BUFFER_START, UploadedBlob};
 
 pub fn upload_blob(ctx: Context<UploadBlob>, index: u32, bytes: Vec<u8>) -> Result<()> {
+    require!(
+        !ctx.accounts.uploaded_blob.finalized,
+        ZkNftError::UploadedBlobFinalized
+    );
+
     let account_info = ctx.accounts.uploaded_blob.to_account_info();
     let mut account_data = account_info.data.borrow_mut();
     account_data[UPLOADED_BLOB_BUFFER_START + (index as usize)
@@ -15,7 +21,7 @@ pub fn upload_blob(ctx: Context<UploadBlob>, index: u32, bytes: Vec<u8>) -> Resu
 pub struct UploadBlob<'info> {
     #[account(
         mut,
-        has_one = authority,
+        constraint = uploaded_blob.can_upload(&authority.key()) @ ZkNftError::InvalidAuthority,
     )]
     pub uploaded_blob: Account<'info, UploadedBlob>,
     pub authority: Signer<'info>,
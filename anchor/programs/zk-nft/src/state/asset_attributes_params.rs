@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Attributes, PackedInputCompressedPda};
+
+/// The `Attributes` of an asset, which `decompress` copies into the token
+/// metadata and `burn` closes together with the asset.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AssetAttributesParams {
+    pub data: Attributes,
+    pub input: PackedInputCompressedPda,
+    pub address_merkle_tree_account_index: u8,
+}
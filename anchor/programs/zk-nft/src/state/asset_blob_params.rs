@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Blob, PackedInputCompressedPda};
+
+/// The `Blob` of an asset, which `burn` closes together with the asset.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AssetBlobParams {
+    pub data: Blob,
+    pub input: PackedInputCompressedPda,
+    pub address_merkle_tree_account_index: u8,
+}
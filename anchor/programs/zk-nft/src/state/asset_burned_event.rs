@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AssetBurnedEvent {
+    pub asset_id: Pubkey,
+}
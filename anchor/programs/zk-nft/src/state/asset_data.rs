@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use light_hasher::{errors::HasherError, Hasher};
 
+use crate::state::{Edition, Royalties};
+
 #[derive(Debug)]
 #[account]
 pub struct AssetData {
@@ -10,6 +12,14 @@ pub struct AssetData {
 
     pub has_attributes: bool,
     pub has_blob: bool,
+    /// Whether the group authority vouches for the asset being a member of `group`.
+    pub verified: bool,
+    pub royalties: Option<Royalties>,
+    /// Soulbound assets can only be moved by their authority, but can still be burned.
+    pub non_transferable: bool,
+    /// Can transfer, lock, unlock and burn the asset regardless of its owner.
+    pub permanent_delegate: Option<Pubkey>,
+    pub edition: Option<Edition>,
 }
 
 impl light_hasher::DataHasher for AssetData {
@@ -19,22 +29,26 @@ impl light_hasher::DataHasher for AssetData {
         let mutable_bytes = [self.mutable as u8];
         hash_inputs.push(&mutable_bytes[..]);
 
-        let hashed_group;
-        if let Some(group) = &self.group {
-            hashed_group = light_utils::hash_to_bn254_field_size_be(group.to_bytes().as_slice())
-                .unwrap()
-                .0;
-            hash_inputs.push(hashed_group.as_slice());
+        // absent keys and settings hash as zeroes, so every field keeps its position
+        let hashed_group = match &self.group {
+            Some(group) => {
+                light_utils::hash_to_bn254_field_size_be(group.to_bytes().as_slice())
+                    .unwrap()
+                    .0
+            }
+            None => [0; 32],
         };
+        hash_inputs.push(hashed_group.as_slice());
 
-        let hashed_authority;
-        if let Some(authority) = &self.authority {
-            hashed_authority =
+        let hashed_authority = match &self.authority {
+            Some(authority) => {
                 light_utils::hash_to_bn254_field_size_be(authority.to_bytes().as_slice())
                     .unwrap()
-                    .0;
-            hash_inputs.push(hashed_authority.as_slice());
+                    .0
+            }
+            None => [0; 32],
         };
+        hash_inputs.push(hashed_authority.as_slice());
 
         let has_attributes_bytes = [self.has_attributes as u8];
         hash_inputs.push(&has_attributes_bytes[..]);
@@ -42,6 +56,34 @@ impl light_hasher::DataHasher for AssetData {
         let has_blob_bytes = [self.has_blob as u8];
         hash_inputs.push(&has_blob_bytes[..]);
 
+        let verified_bytes = [self.verified as u8];
+        hash_inputs.push(&verified_bytes[..]);
+
+        let hashed_royalties = match &self.royalties {
+            Some(royalties) => royalties.hash_to_field_size(),
+            None => [0; 32],
+        };
+        hash_inputs.push(hashed_royalties.as_slice());
+
+        let non_transferable_bytes = [self.non_transferable as u8];
+        hash_inputs.push(&non_transferable_bytes[..]);
+
+        let hashed_permanent_delegate = match &self.permanent_delegate {
+            Some(permanent_delegate) => {
+                light_utils::hash_to_bn254_field_size_be(permanent_delegate.to_bytes().as_slice())
+                    .unwrap()
+                    .0
+            }
+            None => [0; 32],
+        };
+        hash_inputs.push(hashed_permanent_delegate.as_slice());
+
+        let hashed_edition = match &self.edition {
+            Some(edition) => edition.hash_to_field_size(),
+            None => [0; 32],
+        };
+        hash_inputs.push(hashed_edition.as_slice());
+
         H::hashv(hash_inputs.as_slice())
     }
 }
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AssetData, PackedInputCompressedPda};
+
+/// An asset's current `AssetData`, with the context needed to consume it.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AssetDataParams {
+    pub data: AssetData,
+    pub input: PackedInputCompressedPda,
+    pub address_merkle_tree_account_index: u8,
+}
@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AssetDecompressedEvent {
+    pub asset_id: Pubkey,
+    pub mint: Pubkey,
+}
@@ -34,13 +34,16 @@ impl light_hasher::DataHasher for Attributes {
             .map(|hash| hash.as_slice())
             .collect::<Vec<&[u8]>>();
 
-        let hashed_group;
-        if let Some(group) = &self.group {
-            hashed_group = light_utils::hash_to_bn254_field_size_be(group.to_bytes().as_slice())
-                .unwrap()
-                .0;
-            hash_inputs.push(hashed_group.as_slice());
+        // ungrouped attributes end in zeroes, which no attribute hash can equal
+        let hashed_group = match &self.group {
+            Some(group) => {
+                light_utils::hash_to_bn254_field_size_be(group.to_bytes().as_slice())
+                    .unwrap()
+                    .0
+            }
+            None => [0; 32],
         };
+        hash_inputs.push(hashed_group.as_slice());
 
         H::hashv(&hash_inputs.as_slice())
     }
@@ -8,6 +8,38 @@ pub struct BaseData {
     pub delegate: Option<Pubkey>,
     pub delegate_role: DelegateRole,
     pub state: State,
+    /// Unix timestamp after which the delegate loses its privileges and a rental ends.
+    pub delegate_expiry: Option<i64>,
+    /// Sale price while the asset is listed, in lamports or `payment_mint` tokens.
+    pub price: Option<u64>,
+    /// Token mint the sale price is in, lamports when unset.
+    pub payment_mint: Option<Pubkey>,
+}
+
+impl BaseData {
+    pub fn is_delegate_expired(&self, now: i64) -> bool {
+        self.delegate_expiry.is_some_and(|expiry| now >= expiry)
+    }
+
+    /// The delegate, as long as its delegation has not expired.
+    pub fn active_delegate(&self, now: i64) -> Option<Pubkey> {
+        if self.is_delegate_expired(now) {
+            None
+        } else {
+            self.delegate
+        }
+    }
+
+    /// The lock state, with expired rentals and delegate locks unlocked.
+    pub fn effective_state(&self, now: i64) -> State {
+        if matches!(self.state, State::Rented | State::LockedByDelegate)
+            && self.is_delegate_expired(now)
+        {
+            State::Unlocked
+        } else {
+            self.state
+        }
+    }
 }
 
 impl light_hasher::DataHasher for BaseData {
@@ -24,22 +56,51 @@ impl light_hasher::DataHasher for BaseData {
         let state_bytes = [self.state as u8];
         hash_inputs.push(&state_bytes[..]);
 
-        let hashed_delegate;
-        if let Some(delegate) = self.delegate {
-            hashed_delegate =
+        // an unset delegate hashes as zeroes, keeping the role in its position
+        let hashed_delegate = match self.delegate {
+            Some(delegate) => {
                 light_utils::hash_to_bn254_field_size_be(delegate.to_bytes().as_slice())
                     .unwrap()
-                    .0;
-            hash_inputs.push(hashed_delegate.as_slice());
+                    .0
+            }
+            None => [0; 32],
         };
+        hash_inputs.push(hashed_delegate.as_slice());
 
         let delegate_role_bytes = [self.delegate_role as u8];
         hash_inputs.push(&delegate_role_bytes[..]);
 
+        // numbers are prefixed with a presence byte, so that unset and 0 hash differently
+        let delegate_expiry_bytes = option_to_bytes(self.delegate_expiry.map(i64::to_be_bytes));
+        hash_inputs.push(&delegate_expiry_bytes[..]);
+
+        let price_bytes = option_to_bytes(self.price.map(u64::to_be_bytes));
+        hash_inputs.push(&price_bytes[..]);
+
+        let hashed_payment_mint = match self.payment_mint {
+            Some(payment_mint) => {
+                light_utils::hash_to_bn254_field_size_be(payment_mint.to_bytes().as_slice())
+                    .unwrap()
+                    .0
+            }
+            None => [0; 32],
+        };
+        hash_inputs.push(hashed_payment_mint.as_slice());
+
         H::hashv(hash_inputs.as_slice())
     }
 }
 
+/// Presence byte followed by the value, or zeroes when unset.
+fn option_to_bytes(value: Option<[u8; 8]>) -> [u8; 9] {
+    let mut bytes = [0; 9];
+    if let Some(value) = value {
+        bytes[0] = 1;
+        bytes[1..].copy_from_slice(&value);
+    }
+    bytes
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, Default)]
 #[repr(u8)]
 pub enum State {
@@ -47,6 +108,11 @@ pub enum State {
     Unlocked,
     LockedByAuthority,
     LockedByDelegate,
+    LockedByPermanentDelegate,
+    /// The delegate uses the asset until its delegation expires, the owner keeps title.
+    Rented,
+    /// The asset lives on as a Token-2022 NFT until it is compressed again.
+    Decompressed,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, Default)]
@@ -60,4 +126,38 @@ pub enum DelegateRole {
     TransferAndLock,
     TransferAndBurn,
     LockAndBurn,
+    /// Renter, with no privileges besides being the asset's current user.
+    Use,
+}
+
+impl DelegateRole {
+    pub fn can_transfer(&self) -> bool {
+        matches!(
+            self,
+            DelegateRole::All
+                | DelegateRole::Transfer
+                | DelegateRole::TransferAndLock
+                | DelegateRole::TransferAndBurn
+        )
+    }
+
+    pub fn can_lock(&self) -> bool {
+        matches!(
+            self,
+            DelegateRole::All
+                | DelegateRole::Lock
+                | DelegateRole::TransferAndLock
+                | DelegateRole::LockAndBurn
+        )
+    }
+
+    pub fn can_burn(&self) -> bool {
+        matches!(
+            self,
+            DelegateRole::All
+                | DelegateRole::Burn
+                | DelegateRole::TransferAndBurn
+                | DelegateRole::LockAndBurn
+        )
+    }
 }
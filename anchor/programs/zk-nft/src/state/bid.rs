@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use light_hasher::{errors::HasherError, Hasher};
+
+use crate::utils::get_asset_resource_seed;
+
+/// Offer of `amount` lamports for an asset. The offered lamports are escrowed
+/// in the bid's compressed account until it is accepted or cancelled.
+#[derive(Debug)]
+#[account]
+pub struct Bid {
+    pub asset_id: [u8; 32],
+    pub bidder: Pubkey,
+    /// Chosen by the bidder, distinct among their bids for the asset.
+    pub bid_id: u64,
+    pub amount: u64,
+}
+
+impl Bid {
+    /// Address seed of the bid. A bidder raises a bid by placing one with a new
+    /// `bid_id` and cancelling the old one.
+    pub fn seed(asset_id: &[u8; 32], bidder: &Pubkey, bid_id: u64) -> Result<[u8; 32]> {
+        get_asset_resource_seed(
+            &[b"bid".as_slice(), bidder.as_ref(), &bid_id.to_le_bytes()].concat(),
+            asset_id,
+        )
+    }
+}
+
+impl light_hasher::DataHasher for Bid {
+    fn hash<H: Hasher>(&self) -> std::result::Result<[u8; 32], HasherError> {
+        let hashed_asset_id = light_utils::hash_to_bn254_field_size_be(self.asset_id.as_slice())
+            .unwrap()
+            .0;
+        let hashed_bidder =
+            light_utils::hash_to_bn254_field_size_be(self.bidder.to_bytes().as_slice())
+                .unwrap()
+                .0;
+        let bid_id_bytes = self.bid_id.to_be_bytes();
+        let amount_bytes = self.amount.to_be_bytes();
+
+        H::hashv(
+            vec![
+                hashed_asset_id.as_slice(),
+                hashed_bidder.as_slice(),
+                &bid_id_bytes[..],
+                &amount_bytes[..],
+            ]
+            .as_slice(),
+        )
+    }
+}
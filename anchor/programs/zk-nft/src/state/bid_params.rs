@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Bid, PackedInputCompressedPda};
+
+/// A bid's current data, consumed when it is accepted or cancelled.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct BidParams {
+    pub data: Bid,
+    pub input: PackedInputCompressedPda,
+    pub address_merkle_tree_account_index: u8,
+}
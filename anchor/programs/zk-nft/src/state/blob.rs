@@ -1,11 +1,46 @@
 use anchor_lang::prelude::*;
 use light_hasher::{errors::HasherError, Hasher};
 
+use crate::state::SharedBlob;
+
 #[derive(Debug)]
 #[account]
 pub struct Blob {
     pub content_type: String,
+    pub content_encoding: ContentEncoding,
     pub tx_sig: [u8; 64],
+    /// Creator of the `SharedBlob` this blob links to, if any, found by `hash`.
+    pub shared_blob_creator: Option<Pubkey>,
+    /// Finalized `UploadedBlob` this blob was minted from, if any.
+    pub uploaded_blob: Option<Pubkey>,
+    /// sha256 of the logged bytes, checked against `uploaded_blob` or the
+    /// linked `SharedBlob` at mint time.
+    pub hash: Option<[u8; 32]>,
+}
+
+impl Blob {
+    pub fn to_shared_blob(&self) -> Option<SharedBlob> {
+        let (creator, hash) = self.shared_blob_creator.zip(self.hash)?;
+        Some(SharedBlob {
+            creator,
+            hash,
+            content_type: self.content_type.clone(),
+            content_encoding: self.content_encoding,
+            tx_sig: self.tx_sig,
+        })
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, Default, InitSpace,
+)]
+#[repr(u8)]
+pub enum ContentEncoding {
+    #[default]
+    Identity,
+    Gzip,
+    Zstd,
+    Brotli,
 }
 
 impl light_hasher::DataHasher for Blob {
@@ -14,10 +49,48 @@ impl light_hasher::DataHasher for Blob {
             light_utils::hash_to_bn254_field_size_be(self.content_type.as_bytes())
                 .unwrap()
                 .0;
+        let content_encoding_bytes = [self.content_encoding as u8];
         let hashed_tx_sig = light_utils::hash_to_bn254_field_size_be(self.tx_sig.as_slice())
             .unwrap()
             .0;
 
-        H::hashv(vec![hashed_content_type.as_slice(), hashed_tx_sig.as_slice()].as_slice())
+        let mut hash_inputs = vec![
+            hashed_content_type.as_slice(),
+            &content_encoding_bytes[..],
+            hashed_tx_sig.as_slice(),
+        ];
+
+        // a blob is either shared or uploaded, the other one hashes as zeroes
+        let hashed_shared_blob_creator = match &self.shared_blob_creator {
+            Some(shared_blob_creator) => {
+                light_utils::hash_to_bn254_field_size_be(shared_blob_creator.to_bytes().as_slice())
+                    .unwrap()
+                    .0
+            }
+            None => [0; 32],
+        };
+        hash_inputs.push(hashed_shared_blob_creator.as_slice());
+
+        let hashed_uploaded_blob = match &self.uploaded_blob {
+            Some(uploaded_blob) => {
+                light_utils::hash_to_bn254_field_size_be(uploaded_blob.to_bytes().as_slice())
+                    .unwrap()
+                    .0
+            }
+            None => [0; 32],
+        };
+        hash_inputs.push(hashed_uploaded_blob.as_slice());
+
+        let hashed_hash = match &self.hash {
+            Some(hash) => {
+                light_utils::hash_to_bn254_field_size_be(hash.as_slice())
+                    .unwrap()
+                    .0
+            }
+            None => [0; 32],
+        };
+        hash_inputs.push(hashed_hash.as_slice());
+
+        H::hashv(hash_inputs.as_slice())
     }
 }
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Header prepended to every chunk `log_blob` writes through spl-noop, so that
+/// indexers can reassemble blobs from transaction logs.
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct BlobChunkHeader {
+    pub uploaded_blob: Pubkey,
+    pub index: u32,
+    pub total_chunks: u32,
+    /// sha256 of the whole blob buffer
+    pub hash: [u8; 32],
+}
+
+impl BlobChunkHeader {
+    pub const LEN: usize = 32 + // uploaded blob
+        4 + // index
+        4 + // total chunks
+        32; // hash
+}
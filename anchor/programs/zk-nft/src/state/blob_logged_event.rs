@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct BlobLoggedEvent {
+    pub uploaded_blob: Pubkey,
+    pub total_chunks: u32,
+    pub total_bytes: u32,
+    pub hash: [u8; 32],
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum Edition {
+    /// Master asset with `supply` prints so far, open when `max_supply` is not set.
+    Master {
+        supply: u64,
+        max_supply: Option<u64>,
+    },
+    /// Numbered copy of the `parent` master, sharing its blob and attributes.
+    Print { parent: [u8; 32], number: u64 },
+}
+
+impl Edition {
+    pub fn hash_to_field_size(&self) -> [u8; 32] {
+        light_utils::hash_to_bn254_field_size_be(self.try_to_vec().unwrap().as_slice())
+            .unwrap()
+            .0
+    }
+}
@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct EditionPrintedEvent {
+    pub asset_id: Pubkey,
+    pub master_id: Pubkey,
+    pub number: u64,
+}
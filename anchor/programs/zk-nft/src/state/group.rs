@@ -1,9 +1,36 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{MAX_CONTENT_TYPE_LEN, MAX_GROUP_CONTENT_TYPES, MAX_GROUP_DEPTH};
+use crate::state::Royalties;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Group {
     pub size: u64,
     pub max_size: u64,
     pub authority: Pubkey,
+    /// Content types member blobs may carry, compared without parameters. Empty allows any.
+    #[max_len(MAX_GROUP_CONTENT_TYPES, MAX_CONTENT_TYPE_LEN)]
+    pub allowed_content_types: Vec<String>,
+    /// Default royalties of assets minted into the group.
+    pub royalties: Option<Royalties>,
+    /// Makes every asset minted into the group non-transferable.
+    pub non_transferable: bool,
+    /// Permanent delegate of assets minted into the group.
+    pub permanent_delegate: Option<Pubkey>,
+    /// Blocks transfers of every member asset while set.
+    pub frozen: bool,
+    /// Program invoked on every transfer of a member asset, see `zk-nft-transfer-hook-interface`.
+    pub transfer_hook_program: Option<Pubkey>,
+    /// `RecipientPolicy` checked against the recipient of every transfer.
+    pub recipient_policy: Option<Pubkey>,
+    /// Metaplex collection whose verified NFTs are imported into the group.
+    pub collection: Option<Pubkey>,
+    /// Group this one is nested in, such as a franchise owning several collections.
+    pub parent: Option<Pubkey>,
+    /// Ancestors from the root group down to `parent`, fixed at creation.
+    #[max_len(MAX_GROUP_DEPTH)]
+    pub path: Vec<Pubkey>,
+    /// Mint configs created for the group so far, the next config's id.
+    pub mint_configs: u64,
 }
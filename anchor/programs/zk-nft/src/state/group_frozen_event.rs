@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct GroupFrozenEvent {
+    pub group: Pubkey,
+    /// Ancestors of `group`, from the root group down to its parent.
+    pub group_path: Vec<Pubkey>,
+    pub frozen: bool,
+}
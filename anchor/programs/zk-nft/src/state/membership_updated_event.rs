@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MembershipUpdatedEvent {
+    pub asset_id: Pubkey,
+    pub group: Pubkey,
+    /// Ancestors of `group`, from the root group down to its parent.
+    pub group_path: Vec<Pubkey>,
+    pub verified: bool,
+}
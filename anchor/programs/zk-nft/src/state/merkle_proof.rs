@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct MerkleProof {
+    pub index: u32,
+    pub proof: Vec<[u8; 32]>,
+}
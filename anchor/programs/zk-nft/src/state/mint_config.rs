@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_MINT_PHASES;
+use crate::errors::ZkNftError;
+use crate::state::{MerkleProof, RevealConfig};
+use crate::utils::{get_asset_resource_seed, verify_merkle_proof};
+
+/// Public sale of assets into a group. Anyone can mint through
+/// `mint_from_config` while the sale or one of its phases is open, the config
+/// signing for the group.
+#[account]
+#[derive(InitSpace)]
+pub struct MintConfig {
+    pub group: Pubkey,
+    /// Index of the config among the group's configs, part of its address so
+    /// that a closed config's address is never reused.
+    pub id: u64,
+    /// Address tree the assets and mint records are created in.
+    pub address_merkle_tree: Pubkey,
+    /// Receives the mint price.
+    pub treasury: Pubkey,
+    /// In lamports, or `payment_mint` tokens when set.
+    pub price: u64,
+    /// Token mint the prices are in, paid into the treasury's token account.
+    pub payment_mint: Option<Pubkey>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// Mints allowed per wallet in the public sale and again in each open phase,
+    /// tracked in compressed `MintRecord`s.
+    pub wallet_limit: Option<u32>,
+    pub max_supply: Option<u64>,
+    /// Assets minted so far, the next mint's index.
+    pub minted: u64,
+    /// Phases, such as presales, running besides the public sale.
+    #[max_len(MAX_MINT_PHASES)]
+    pub phases: Vec<MintPhase>,
+    /// Set when assets mint with placeholders and are revealed later.
+    pub reveal: Option<RevealConfig>,
+    /// Slot whose hash draws the reveal offset, fixed by `start_reveal`.
+    pub reveal_slot: Option<u64>,
+    /// Shift of the revealed leaves, drawn from the hash of `reveal_slot`.
+    pub reveal_offset: Option<u64>,
+    /// Assets revealed so far, the config closes once all of them are.
+    pub revealed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct MintPhase {
+    /// In the config's currency.
+    pub price: u64,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// Root of a Merkle tree of `AllowlistProof` leaves, see `utils::merkle`.
+    /// Phases without one are open to anyone, up to the config's wallet limit
+    /// within the phase.
+    pub allowlist_root: Option<[u8; 32]>,
+}
+
+/// Proves the minter's wallet is allowed `allocation` mints in a phase. The
+/// leaf is the wallet followed by the little-endian allocation.
+#[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct AllowlistProof {
+    pub allocation: u32,
+    pub proof: MerkleProof,
+}
+
+#[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct MintConfigParams {
+    pub treasury: Pubkey,
+    pub price: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub wallet_limit: Option<u32>,
+    pub max_supply: Option<u64>,
+    pub phases: Vec<MintPhase>,
+    pub reveal: Option<RevealConfig>,
+}
+
+impl MintConfig {
+    pub fn set_params(&mut self, params: MintConfigParams) -> Result<()> {
+        require!(
+            params.phases.len() <= MAX_MINT_PHASES,
+            ZkNftError::TooManyMintPhases
+        );
+        // the committed metadata, and the supply it is drawn from, cannot
+        // change once minting started
+        require!(
+            params.reveal.is_none() || params.max_supply.is_some(),
+            ZkNftError::RevealRequiresMaxSupply
+        );
+        require!(
+            self.minted == 0
+                || (params.reveal == self.reveal
+                    && (self.reveal.is_none() || params.max_supply == self.max_supply)),
+            ZkNftError::RevealLocked
+        );
+        self.treasury = params.treasury;
+        self.price = params.price;
+        self.payment_mint = params.payment_mint;
+        self.start_time = params.start_time;
+        self.end_time = params.end_time;
+        self.wallet_limit = params.wallet_limit;
+        self.max_supply = params.max_supply;
+        self.phases = params.phases;
+        self.reveal = params.reveal;
+        Ok(())
+    }
+
+    /// Address seed of the asset minted at `index` from `mint_config`.
+    pub fn asset_seed(mint_config: &Pubkey, index: u64) -> Result<[u8; 32]> {
+        get_asset_resource_seed(
+            &[b"mint".as_slice(), &index.to_le_bytes()].concat(),
+            &mint_config.to_bytes(),
+        )
+    }
+
+    /// Price and wallet limit of a mint by `wallet` in `phase`, or in the
+    /// public sale when no phase is given. The limit applies to the wallet's
+    /// mints in that phase or sale only.
+    pub fn mint_terms(
+        &self,
+        now: i64,
+        wallet: &Pubkey,
+        phase: Option<u8>,
+        allowlist_proof: Option<&AllowlistProof>,
+    ) -> Result<(u64, Option<u32>)> {
+        let Some(phase) = phase else {
+            require!(
+                is_open(self.start_time, self.end_time, now),
+                ZkNftError::MintNotOpen
+            );
+            return Ok((self.price, self.wallet_limit));
+        };
+
+        let phase = self
+            .phases
+            .get(phase as usize)
+            .ok_or(ZkNftError::InvalidMintPhase)?;
+        require!(
+            is_open(phase.start_time, phase.end_time, now),
+            ZkNftError::MintNotOpen
+        );
+        let Some(allowlist_root) = phase.allowlist_root else {
+            return Ok((phase.price, self.wallet_limit));
+        };
+        let allowlist_proof = allowlist_proof.ok_or(ZkNftError::NotAllowlisted)?;
+        require!(
+            verify_merkle_proof(
+                allowlist_root,
+                &[wallet.as_ref(), &allowlist_proof.allocation.to_le_bytes()].concat(),
+                &allowlist_proof.proof,
+            ),
+            ZkNftError::NotAllowlisted
+        );
+        Ok((phase.price, Some(allowlist_proof.allocation)))
+    }
+
+    /// Whether no more assets can be minted, the public sale and every phase
+    /// being sold out or over.
+    pub fn is_closed(&self, now: i64) -> bool {
+        self.max_supply
+            .is_some_and(|max_supply| self.minted >= max_supply)
+            || (self.end_time.is_some_and(|end_time| now >= end_time)
+                && self
+                    .phases
+                    .iter()
+                    .all(|phase| phase.end_time.is_some_and(|end_time| now >= end_time)))
+    }
+
+    /// Merkle tree leaf revealed by the asset minted at `index`.
+    pub fn reveal_leaf_index(&self, index: u64) -> Result<u64> {
+        let reveal_offset = self.reveal_offset.ok_or(ZkNftError::RevealNotStarted)?;
+        let max_supply = self.max_supply.ok_or(ZkNftError::RevealRequiresMaxSupply)?;
+        Ok(((index as u128 + reveal_offset as u128) % max_supply as u128) as u64)
+    }
+}
+
+fn is_open(start_time: Option<i64>, end_time: Option<i64>, now: i64) -> bool {
+    start_time.map_or(true, |start_time| now >= start_time)
+        && end_time.map_or(true, |end_time| now < end_time)
+}
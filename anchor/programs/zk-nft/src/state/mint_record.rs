@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use light_hasher::{errors::HasherError, Hasher};
+
+use crate::state::PackedInputCompressedPda;
+use crate::utils::get_asset_resource_seed;
+
+/// Number of assets a wallet minted from a `MintConfig`, in one of its phases
+/// or in its public sale.
+#[derive(Debug)]
+#[account]
+pub struct MintRecord {
+    pub mint_config: Pubkey,
+    pub wallet: Pubkey,
+    pub phase: Option<u8>,
+    pub count: u32,
+}
+
+impl MintRecord {
+    /// Address seed of the record, there is one per config, wallet and phase.
+    pub fn seed(mint_config: &Pubkey, wallet: &Pubkey, phase: Option<u8>) -> Result<[u8; 32]> {
+        let phase_bytes = match phase {
+            Some(phase) => vec![1, phase],
+            None => vec![0],
+        };
+        get_asset_resource_seed(
+            &[b"mint_record".as_slice(), wallet.as_ref(), &phase_bytes].concat(),
+            &mint_config.to_bytes(),
+        )
+    }
+}
+
+impl light_hasher::DataHasher for MintRecord {
+    fn hash<H: Hasher>(&self) -> std::result::Result<[u8; 32], HasherError> {
+        let hashed_mint_config =
+            light_utils::hash_to_bn254_field_size_be(self.mint_config.to_bytes().as_slice())
+                .unwrap()
+                .0;
+        let hashed_wallet =
+            light_utils::hash_to_bn254_field_size_be(self.wallet.to_bytes().as_slice())
+                .unwrap()
+                .0;
+        let count_bytes = self.count.to_be_bytes();
+
+        let mut hash_inputs = vec![
+            hashed_mint_config.as_slice(),
+            hashed_wallet.as_slice(),
+            &count_bytes[..],
+        ];
+
+        // presence byte first, so that no phase and phase 0 hash differently
+        let phase_bytes = match self.phase {
+            Some(phase) => [1, phase],
+            None => [0, 0],
+        };
+        hash_inputs.push(&phase_bytes[..]);
+
+        H::hashv(hash_inputs.as_slice())
+    }
+}
+
+/// A wallet's existing mint record, updated on its next mint.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct MintRecordParams {
+    pub data: MintRecord,
+    pub input: PackedInputCompressedPda,
+    pub address_merkle_tree_account_index: u8,
+}
@@ -1,19 +1,61 @@
+pub use asset_attributes_params::*;
+pub use asset_blob_params::*;
+pub use asset_burned_event::*;
 pub use asset_data::*;
+pub use asset_data_params::*;
+pub use asset_decompressed_event::*;
 pub use attributes::*;
 pub use base_data::*;
+pub use bid::*;
+pub use bid_params::*;
 pub use blob::*;
+pub use blob_chunk_header::*;
+pub use blob_logged_event::*;
+pub use edition::*;
+pub use edition_printed_event::*;
 pub use group::*;
+pub use group_frozen_event::*;
+pub use membership_updated_event::*;
+pub use merkle_proof::*;
+pub use mint_config::*;
+pub use mint_record::*;
 pub use new_address_params::*;
 pub use owner_updated_event::*;
 pub use packed_input_compressed_pda::*;
+pub use permanent_delegate_event::*;
+pub use recipient_policy::*;
+pub use reveal::*;
+pub use royalties::*;
+pub use shared_blob::*;
 pub use uploaded_blob::*;
 
+mod asset_attributes_params;
+mod asset_blob_params;
+mod asset_burned_event;
 mod asset_data;
+mod asset_data_params;
+mod asset_decompressed_event;
 mod attributes;
 mod base_data;
+mod bid;
+mod bid_params;
 mod blob;
+mod blob_chunk_header;
+mod blob_logged_event;
+mod edition;
+mod edition_printed_event;
 mod group;
+mod group_frozen_event;
+mod membership_updated_event;
+mod merkle_proof;
+mod mint_config;
+mod mint_record;
 mod new_address_params;
 mod owner_updated_event;
 mod packed_input_compressed_pda;
+mod permanent_delegate_event;
+mod recipient_policy;
+mod reveal;
+mod royalties;
+mod shared_blob;
 mod uploaded_blob;
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use light_system_program::NewAddressParamsPacked;
 
 #[derive(Debug, PartialEq, Default, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
 pub struct NewAddressParams {
@@ -6,3 +7,14 @@ pub struct NewAddressParams {
     pub address_merkle_tree_account_index: u8,
     pub address_merkle_tree_root_index: u16,
 }
+
+impl NewAddressParams {
+    pub fn pack(&self, seed: [u8; 32]) -> NewAddressParamsPacked {
+        NewAddressParamsPacked {
+            seed,
+            address_merkle_tree_account_index: self.address_merkle_tree_account_index,
+            address_queue_account_index: self.address_queue_account_index,
+            address_merkle_tree_root_index: self.address_merkle_tree_root_index,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PermanentDelegateEvent {
+    pub asset_id: Pubkey,
+    pub permanent_delegate: Pubkey,
+    pub action: PermanentDelegateAction,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum PermanentDelegateAction {
+    Transfer,
+    Lock,
+    Unlock,
+    Burn,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::MerkleProof;
+use crate::utils::verify_merkle_proof;
+
+/// Group-level restriction on who can receive member assets. `root` is the root
+/// of a Merkle tree of owner keys sorted in ascending order, see `utils::merkle`.
+#[account]
+#[derive(InitSpace)]
+pub struct RecipientPolicy {
+    pub group: Pubkey,
+    pub mode: RecipientPolicyMode,
+    pub root: [u8; 32],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+#[repr(u8)]
+pub enum RecipientPolicyMode {
+    /// Only owners in the tree can receive assets.
+    Allowlist,
+    /// Owners in the tree cannot receive assets. The tree must contain the
+    /// `Pubkey::default()` and `[255; 32]` sentinels, so that every other key
+    /// falls between two adjacent leaves.
+    Denylist,
+}
+
+#[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize)]
+pub enum RecipientProof {
+    Membership(MerkleProof),
+    /// Adjacent leaves `low` and `high` enclosing the recipient.
+    NonMembership {
+        low: Pubkey,
+        low_proof: MerkleProof,
+        high: Pubkey,
+        high_proof: MerkleProof,
+    },
+}
+
+impl RecipientPolicy {
+    pub fn check_recipient(
+        &self,
+        recipient: &Pubkey,
+        proof: Option<&RecipientProof>,
+    ) -> Result<()> {
+        match (self.mode, proof) {
+            (RecipientPolicyMode::Allowlist, Some(RecipientProof::Membership(proof))) => {
+                require!(
+                    verify_merkle_proof(self.root, recipient.as_ref(), proof),
+                    ZkNftError::RecipientNotAllowed
+                );
+            }
+            (
+                RecipientPolicyMode::Denylist,
+                Some(RecipientProof::NonMembership {
+                    low,
+                    low_proof,
+                    high,
+                    high_proof,
+                }),
+            ) => {
+                require!(
+                    low < recipient
+                        && recipient < high
+                        && low_proof.index.checked_add(1) == Some(high_proof.index)
+                        && verify_merkle_proof(self.root, low.as_ref(), low_proof)
+                        && verify_merkle_proof(self.root, high.as_ref(), high_proof),
+                    ZkNftError::RecipientNotAllowed
+                );
+            }
+            _ => return Err(ZkNftError::InvalidRecipientProof.into()),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CONTENT_TYPE_LEN;
+use crate::state::{
+    Attribute, Attributes, Blob, ContentEncoding, MerkleProof, PackedInputCompressedPda,
+};
+use crate::utils::verify_merkle_proof;
+
+/// Hidden metadata of a mint config. Assets mint with the `placeholder` blob
+/// and empty attributes, and are revealed against `root`, the root of a Merkle
+/// tree with one leaf per mint index, see `RevealConfig::leaf`. Which leaf an
+/// asset reveals is shifted by the config's `reveal_offset`, drawn once minting
+/// is closed.
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct RevealConfig {
+    pub root: [u8; 32],
+    pub placeholder: PlaceholderBlob,
+}
+
+/// Copy of the placeholder `SharedBlob`, at the address given by its `creator`
+/// and `hash`. Minted assets link it without reading the record itself.
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct PlaceholderBlob {
+    pub creator: Pubkey,
+    pub hash: [u8; 32],
+    #[max_len(MAX_CONTENT_TYPE_LEN)]
+    pub content_type: String,
+    pub content_encoding: ContentEncoding,
+    pub tx_sig: [u8; 64],
+}
+
+impl PlaceholderBlob {
+    /// Blob of an unrevealed asset.
+    pub fn to_blob(&self) -> Blob {
+        Blob {
+            content_type: self.content_type.clone(),
+            content_encoding: self.content_encoding,
+            tx_sig: self.tx_sig,
+            shared_blob_creator: Some(self.creator),
+            uploaded_blob: None,
+            hash: Some(self.hash),
+        }
+    }
+
+    /// Whether `blob` links this placeholder, that is its asset is unrevealed.
+    pub fn is_linked_by(&self, blob: &Blob) -> bool {
+        blob.shared_blob_creator == Some(self.creator) && blob.hash == Some(self.hash)
+    }
+}
+
+impl RevealConfig {
+    /// Leaf data at `leaf_index`: the little-endian index followed by the
+    /// borsh-serialized blob and attributes.
+    pub fn leaf(leaf_index: u64, blob: &Blob, attributes: &Vec<Attribute>) -> Vec<u8> {
+        [
+            leaf_index.to_le_bytes().as_slice(),
+            &blob.try_to_vec().unwrap(),
+            &attributes.try_to_vec().unwrap(),
+        ]
+        .concat()
+    }
+
+    pub fn verify(
+        &self,
+        leaf_index: u64,
+        blob: &Blob,
+        attributes: &Vec<Attribute>,
+        proof: &MerkleProof,
+    ) -> bool {
+        proof.index as u64 == leaf_index
+            && verify_merkle_proof(self.root, &Self::leaf(leaf_index, blob, attributes), proof)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RevealParams {
+    /// Mint index of the asset.
+    pub index: u64,
+    pub blob: Blob,
+    pub attributes: Vec<Attribute>,
+    pub proof: MerkleProof,
+    pub placeholder_blob: Blob,
+    pub placeholder_blob_input: PackedInputCompressedPda,
+    pub placeholder_attributes: Attributes,
+    pub placeholder_attributes_input: PackedInputCompressedPda,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CREATORS;
+use crate::errors::ZkNftError;
+
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct Royalties {
+    pub basis_points: u16,
+    /// When set, `transfer` pays the creators out of a declared sale price.
+    pub enforced: bool,
+    #[max_len(MAX_CREATORS)]
+    pub creators: Vec<Creator>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct Creator {
+    pub address: Pubkey,
+    /// Percentage of the royalties, all shares sum up to 100
+    pub share: u8,
+}
+
+impl Royalties {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.basis_points <= 10_000
+                && self.creators.len() <= MAX_CREATORS
+                && self
+                    .creators
+                    .iter()
+                    .map(|creator| creator.share as u16)
+                    .sum::<u16>()
+                    == 100,
+            ZkNftError::InvalidRoyalties
+        );
+        Ok(())
+    }
+
+    /// Amount owed to each creator for a sale at `sale_price` lamports.
+    pub fn creator_amounts(&self, sale_price: u64) -> Vec<(Pubkey, u64)> {
+        let royalties = sale_price as u128 * self.basis_points as u128 / 10_000;
+        self.creators
+            .iter()
+            .map(|creator| {
+                (
+                    creator.address,
+                    (royalties * creator.share as u128 / 100) as u64,
+                )
+            })
+            .collect()
+    }
+
+    pub fn hash_to_field_size(&self) -> [u8; 32] {
+        light_utils::hash_to_bn254_field_size_be(self.try_to_vec().unwrap().as_slice())
+            .unwrap()
+            .0
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use light_hasher::{errors::HasherError, Hasher};
+
+use crate::state::{Blob, ContentEncoding};
+use crate::utils::get_asset_resource_seed;
+
+/// Content-addressed blob record, logged once and linked by any number of assets.
+/// Its address is derived from its `creator` and the sha256 `hash` of the logged
+/// bytes, so that nobody else can claim a hash first with different content.
+#[derive(Debug)]
+#[account]
+pub struct SharedBlob {
+    pub creator: Pubkey,
+    pub hash: [u8; 32],
+    pub content_type: String,
+    pub content_encoding: ContentEncoding,
+    pub tx_sig: [u8; 64],
+}
+
+impl SharedBlob {
+    /// Address seed of the record, there is at most one per creator and hash.
+    pub fn seed(creator: &Pubkey, hash: &[u8; 32]) -> Result<[u8; 32]> {
+        get_asset_resource_seed(
+            &[b"shared_blob".as_slice(), creator.as_ref()].concat(),
+            hash,
+        )
+    }
+
+    /// Blob of an asset linking to this record.
+    pub fn to_blob(&self) -> Blob {
+        Blob {
+            content_type: self.content_type.clone(),
+            content_encoding: self.content_encoding,
+            tx_sig: self.tx_sig,
+            shared_blob_creator: Some(self.creator),
+            uploaded_blob: None,
+            hash: Some(self.hash),
+        }
+    }
+}
+
+impl light_hasher::DataHasher for SharedBlob {
+    fn hash<H: Hasher>(&self) -> std::result::Result<[u8; 32], HasherError> {
+        let hashed_creator =
+            light_utils::hash_to_bn254_field_size_be(self.creator.to_bytes().as_slice())
+                .unwrap()
+                .0;
+        let hashed_hash = light_utils::hash_to_bn254_field_size_be(self.hash.as_slice())
+            .unwrap()
+            .0;
+        let hashed_content_type =
+            light_utils::hash_to_bn254_field_size_be(self.content_type.as_bytes())
+                .unwrap()
+                .0;
+        let content_encoding_bytes = [self.content_encoding as u8];
+        let hashed_tx_sig = light_utils::hash_to_bn254_field_size_be(self.tx_sig.as_slice())
+            .unwrap()
+            .0;
+
+        H::hashv(
+            vec![
+                hashed_creator.as_slice(),
+                hashed_hash.as_slice(),
+                hashed_content_type.as_slice(),
+                &content_encoding_bytes[..],
+                hashed_tx_sig.as_slice(),
+            ]
+            .as_slice(),
+        )
+    }
+}
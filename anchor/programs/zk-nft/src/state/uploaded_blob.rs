@@ -1,7 +1,23 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::MAX_BLOB_UPLOADERS;
+use crate::state::ContentEncoding;
+
 #[account]
 pub struct UploadedBlob {
     pub authority: Pubkey,
+    pub content_encoding: ContentEncoding,
+    /// Additional keys allowed to upload and log the buffer. Unused slots are `Pubkey::default()`.
+    pub uploaders: [Pubkey; MAX_BLOB_UPLOADERS],
+    /// Set once the buffer has been logged, after which it can no longer be written.
+    pub finalized: bool,
+    /// sha256 of the buffer, set when finalized
+    pub hash: [u8; 32],
     // actual json buffer is hidden to avoid deserialization
 }
+
+impl UploadedBlob {
+    pub fn can_upload(&self, key: &Pubkey) -> bool {
+        self.authority == *key || (*key != Pubkey::default() && self.uploaders.contains(key))
+    }
+}
@@ -0,0 +1,179 @@
+use anchor_lang::{prelude::*, Discriminator};
+use light_hasher::{DataHasher, Poseidon};
+use light_system_program::sdk::{
+    address::derive_address,
+    compressed_account::{
+        CompressedAccount, CompressedAccountData, PackedCompressedAccountWithMerkleContext,
+    },
+};
+use light_system_program::OutputCompressedAccountWithPackedContext;
+
+use crate::state::{
+    AssetAttributesParams, AssetDataParams, Bid, BidParams, PackedInputCompressedPda,
+};
+use crate::utils::get_asset_resource_seed;
+
+pub fn derive_asset_resource_address(
+    remaining_accounts: &[AccountInfo],
+    address_merkle_tree_account_index: u8,
+    resource: &[u8],
+    asset_id: &[u8; 32],
+) -> Result<[u8; 32]> {
+    let seed = get_asset_resource_seed(resource, asset_id)?;
+    derive_address(
+        &remaining_accounts[address_merkle_tree_account_index as usize].key(),
+        &seed,
+    )
+    .map_err(|_| ProgramError::InvalidArgument.into())
+}
+
+pub fn get_compressed_account_data<T>(data: &T) -> Result<CompressedAccountData>
+where
+    T: AnchorSerialize + Discriminator + DataHasher,
+{
+    Ok(CompressedAccountData {
+        discriminator: T::discriminator(),
+        data: data.try_to_vec().unwrap(),
+        data_hash: data.hash::<Poseidon>().map_err(ProgramError::from)?,
+    })
+}
+
+/// Builds a compressed account holding `data` at a new `address`.
+pub fn get_new_compressed_pda<T>(
+    address: [u8; 32],
+    data: &T,
+) -> Result<OutputCompressedAccountWithPackedContext>
+where
+    T: AnchorSerialize + Discriminator + DataHasher,
+{
+    Ok(OutputCompressedAccountWithPackedContext {
+        compressed_account: CompressedAccount {
+            owner: crate::ID,
+            lamports: 0,
+            address: Some(address),
+            data: Some(get_compressed_account_data(data)?),
+        },
+        merkle_tree_index: 0,
+    })
+}
+
+/// Restores the compressed account `data` was read from, to consume it.
+pub fn get_old_compressed_pda<T>(
+    address: [u8; 32],
+    input: &PackedInputCompressedPda,
+    data: &T,
+) -> Result<PackedCompressedAccountWithMerkleContext>
+where
+    T: AnchorSerialize + Discriminator + DataHasher,
+{
+    Ok(PackedCompressedAccountWithMerkleContext {
+        compressed_account: CompressedAccount {
+            owner: crate::ID,
+            lamports: 0,
+            address: Some(address),
+            data: Some(get_compressed_account_data(data)?),
+        },
+        merkle_context: input.merkle_context,
+        root_index: input.root_index,
+    })
+}
+
+/// Restores the compressed account `old_data` was read from and builds its
+/// replacement holding `new_data` at the same address.
+pub fn get_old_and_new_compressed_pda<T>(
+    address: [u8; 32],
+    input: &PackedInputCompressedPda,
+    old_data: &T,
+    new_data: &T,
+) -> Result<(
+    PackedCompressedAccountWithMerkleContext,
+    OutputCompressedAccountWithPackedContext,
+)>
+where
+    T: AnchorSerialize + Discriminator + DataHasher,
+{
+    let old_compressed_account_with_context = get_old_compressed_pda(address, input, old_data)?;
+
+    let new_compressed_account = OutputCompressedAccountWithPackedContext {
+        compressed_account: CompressedAccount {
+            owner: crate::ID,
+            lamports: 0,
+            address: Some(address),
+            data: Some(get_compressed_account_data(new_data)?),
+        },
+        merkle_tree_index: input.merkle_context.merkle_tree_pubkey_index,
+    };
+
+    Ok((old_compressed_account_with_context, new_compressed_account))
+}
+
+/// Consumes and recreates the asset data unchanged, proving it belongs to `asset_id`.
+pub fn get_unchanged_asset_data_compressed_pda(
+    remaining_accounts: &[AccountInfo],
+    asset_id: &[u8; 32],
+    asset_data_params: &AssetDataParams,
+) -> Result<(
+    PackedCompressedAccountWithMerkleContext,
+    OutputCompressedAccountWithPackedContext,
+)> {
+    let asset_data_address = derive_asset_resource_address(
+        remaining_accounts,
+        asset_data_params.address_merkle_tree_account_index,
+        b"asset_data",
+        asset_id,
+    )?;
+    get_old_and_new_compressed_pda(
+        asset_data_address,
+        &asset_data_params.input,
+        &asset_data_params.data,
+        &asset_data_params.data,
+    )
+}
+
+/// Consumes and recreates the attributes unchanged, proving they belong to `asset_id`.
+pub fn get_unchanged_attributes_compressed_pda(
+    remaining_accounts: &[AccountInfo],
+    asset_id: &[u8; 32],
+    attributes_params: &AssetAttributesParams,
+) -> Result<(
+    PackedCompressedAccountWithMerkleContext,
+    OutputCompressedAccountWithPackedContext,
+)> {
+    let attributes_address = derive_asset_resource_address(
+        remaining_accounts,
+        attributes_params.address_merkle_tree_account_index,
+        b"attributes",
+        asset_id,
+    )?;
+    get_old_and_new_compressed_pda(
+        attributes_address,
+        &attributes_params.input,
+        &attributes_params.data,
+        &attributes_params.data,
+    )
+}
+
+/// Restores a bid's compressed account, together with its escrowed lamports.
+pub fn get_bid_compressed_pda(
+    remaining_accounts: &[AccountInfo],
+    bid_params: &BidParams,
+) -> Result<PackedCompressedAccountWithMerkleContext> {
+    let bid = &bid_params.data;
+    let bid_seed = Bid::seed(&bid.asset_id, &bid.bidder, bid.bid_id)?;
+    let bid_address = derive_address(
+        &remaining_accounts[bid_params.address_merkle_tree_account_index as usize].key(),
+        &bid_seed,
+    )
+    .map_err(|_| ProgramError::InvalidArgument)?;
+
+    Ok(PackedCompressedAccountWithMerkleContext {
+        compressed_account: CompressedAccount {
+            owner: crate::ID,
+            lamports: bid.amount,
+            address: Some(bid_address),
+            data: Some(get_compressed_account_data(bid)?),
+        },
+        merkle_context: bid_params.input.merkle_context,
+        root_index: bid_params.input.root_index,
+    })
+}
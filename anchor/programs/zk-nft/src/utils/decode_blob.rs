@@ -0,0 +1,22 @@
+use std::io::Read;
+
+use crate::state::ContentEncoding;
+
+/// Decodes a logged blob payload back to the original bytes, according to the
+/// `content_encoding` stored in its `Blob`.
+pub fn decode_blob(content_encoding: ContentEncoding, bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut decoded = Vec::new();
+    match content_encoding {
+        ContentEncoding::Identity => decoded.extend_from_slice(bytes),
+        ContentEncoding::Gzip => {
+            flate2::read::GzDecoder::new(bytes).read_to_end(&mut decoded)?;
+        }
+        ContentEncoding::Zstd => {
+            zstd::stream::read::Decoder::new(bytes)?.read_to_end(&mut decoded)?;
+        }
+        ContentEncoding::Brotli => {
+            brotli::Decompressor::new(bytes, 4096).read_to_end(&mut decoded)?;
+        }
+    }
+    Ok(decoded)
+}
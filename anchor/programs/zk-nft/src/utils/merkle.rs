@@ -0,0 +1,27 @@
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::state::MerkleProof;
+
+pub fn merkle_leaf_hash(data: &[u8]) -> [u8; 32] {
+    hashv(&[&[0], data]).to_bytes()
+}
+
+/// Computes the root of the tree containing `leaf` at `proof.index`. Leaves and
+/// nodes are domain separated so a node can never be passed off as a leaf.
+pub fn compute_merkle_root(leaf: [u8; 32], proof: &MerkleProof) -> [u8; 32] {
+    let mut node = leaf;
+    let mut index = proof.index;
+    for sibling in &proof.proof {
+        node = if index % 2 == 0 {
+            hashv(&[&[1], &node, sibling]).to_bytes()
+        } else {
+            hashv(&[&[1], sibling, &node]).to_bytes()
+        };
+        index /= 2;
+    }
+    node
+}
+
+pub fn verify_merkle_proof(root: [u8; 32], data: &[u8], proof: &MerkleProof) -> bool {
+    compute_merkle_root(merkle_leaf_hash(data), proof) == root
+}
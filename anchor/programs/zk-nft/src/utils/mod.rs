@@ -1,3 +1,25 @@
+pub use compressed_pda::*;
+#[cfg(feature = "decode")]
+pub use decode_blob::*;
 pub use get_asset_resource_seed::*;
+pub use merkle::*;
+pub use pay_royalties::*;
+#[cfg(feature = "decode")]
+pub use reassemble_blob::*;
+pub use transfer_lamports::*;
+pub use transfer_restrictions::*;
+pub use transfer_tokens::*;
+pub use validate_content_type::*;
 
+mod compressed_pda;
+#[cfg(feature = "decode")]
+mod decode_blob;
 mod get_asset_resource_seed;
+mod merkle;
+mod pay_royalties;
+#[cfg(feature = "decode")]
+mod reassemble_blob;
+mod transfer_lamports;
+mod transfer_restrictions;
+mod transfer_tokens;
+mod validate_content_type;
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::errors::ZkNftError;
+use crate::state::Royalties;
+use crate::utils::{transfer_lamports, transfer_tokens};
+
+/// Pays each creator their share of the royalties on `sale_price` from `payer`.
+/// Creator accounts are looked up by key in `remaining_accounts`. Returns the
+/// total amount paid.
+pub fn pay_royalties<'info>(
+    payer: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    royalties: &Royalties,
+    sale_price: u64,
+) -> Result<u64> {
+    let mut total = 0u64;
+    for (creator, amount) in royalties.creator_amounts(sale_price) {
+        let creator_account = remaining_accounts
+            .iter()
+            .find(|account| account.key() == creator)
+            .ok_or(ZkNftError::CreatorAccountMissing)?;
+        transfer_lamports(payer, creator_account, amount)?;
+        total = total.checked_add(amount).unwrap();
+    }
+    Ok(total)
+}
+
+/// Pays royalties like `pay_royalties`, in `mint` tokens from the payer's
+/// `from` token account. Each creator is paid into their token account for
+/// `mint`, looked up by owner in `remaining_accounts`.
+pub fn pay_royalties_in_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    payer: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    royalties: &Royalties,
+    sale_price: u64,
+) -> Result<u64> {
+    let mut total = 0u64;
+    for (creator, amount) in royalties.creator_amounts(sale_price) {
+        let creator_token_account = remaining_accounts
+            .iter()
+            .find(|account| {
+                account.owner == token_program.key
+                    && TokenAccount::try_deserialize(&mut &account.data.borrow()[..]).is_ok_and(
+                        |token_account| {
+                            token_account.owner == creator && token_account.mint == mint.key()
+                        },
+                    )
+            })
+            .ok_or(ZkNftError::CreatorAccountMissing)?;
+        transfer_tokens(
+            token_program.clone(),
+            from.clone(),
+            mint,
+            creator_token_account.clone(),
+            payer.clone(),
+            amount,
+        )?;
+        total = total.checked_add(amount).unwrap();
+    }
+    Ok(total)
+}
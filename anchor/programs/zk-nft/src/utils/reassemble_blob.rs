@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ZkNftError;
+use crate::state::BlobChunkHeader;
+
+/// Rebuilds a blob buffer from the noop payloads emitted by `log_blob`, in any
+/// order. Fails if a chunk is missing, belongs to another upload, or the
+/// reassembled bytes do not match the logged hash.
+pub fn reassemble_blob(payloads: &[Vec<u8>]) -> Result<(BlobChunkHeader, Vec<u8>)> {
+    let mut chunks = payloads
+        .iter()
+        .map(|payload| {
+            require!(
+                payload.len() >= BlobChunkHeader::LEN,
+                ZkNftError::InvalidBlobChunks
+            );
+            let header = BlobChunkHeader::try_from_slice(&payload[..BlobChunkHeader::LEN])?;
+            Ok((header, &payload[BlobChunkHeader::LEN..]))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    chunks.sort_by_key(|(header, _)| header.index);
+
+    let first = chunks
+        .first()
+        .map(|(header, _)| header.clone())
+        .ok_or(ZkNftError::InvalidBlobChunks)?;
+    require!(
+        chunks.len() == first.total_chunks as usize,
+        ZkNftError::InvalidBlobChunks
+    );
+
+    let mut blob = Vec::new();
+    for (index, (header, bytes)) in chunks.iter().enumerate() {
+        require!(
+            header.index as usize == index
+                && header.uploaded_blob == first.uploaded_blob
+                && header.total_chunks == first.total_chunks
+                && header.hash == first.hash,
+            ZkNftError::InvalidBlobChunks
+        );
+        blob.extend_from_slice(bytes);
+    }
+    require!(
+        anchor_lang::solana_program::hash::hash(&blob).to_bytes() == first.hash,
+        ZkNftError::InvalidBlobChunks
+    );
+
+    Ok((first, blob))
+}
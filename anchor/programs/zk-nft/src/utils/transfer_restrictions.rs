@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+
+use crate::errors::ZkNftError;
+use crate::state::{AssetData, Group, RecipientPolicy, RecipientProof};
+
+/// Checks the restrictions the asset's group puts on transferring it to
+/// `recipient`, and returns the group's transfer hook program, if any.
+pub fn check_group_transfer_restrictions<'info>(
+    asset_data: &AssetData,
+    group: Option<&Account<'info, Group>>,
+    recipient_policy: Option<&Account<'info, RecipientPolicy>>,
+    recipient: &Pubkey,
+    recipient_proof: Option<&RecipientProof>,
+) -> Result<Option<Pubkey>> {
+    let Some(asset_group) = asset_data.group else {
+        return Ok(None);
+    };
+
+    let group = group.ok_or(ZkNftError::GroupAccountMissing)?;
+    require_keys_eq!(group.key(), asset_group, ZkNftError::GroupMismatch);
+    require!(!group.frozen, ZkNftError::GroupFrozen);
+
+    if let Some(group_recipient_policy) = group.recipient_policy {
+        let recipient_policy = recipient_policy.ok_or(ZkNftError::RecipientPolicyMissing)?;
+        require_keys_eq!(
+            recipient_policy.key(),
+            group_recipient_policy,
+            ZkNftError::RecipientPolicyMissing
+        );
+        recipient_policy.check_recipient(recipient, recipient_proof)?;
+    }
+
+    Ok(group.transfer_hook_program)
+}
+
+/// Returns the transfer hook program of the asset's group, if any, for
+/// transfers by the permanent delegate, which skip the group's other rules.
+pub fn get_group_transfer_hook<'info>(
+    asset_data: &AssetData,
+    group: Option<&Account<'info, Group>>,
+) -> Result<Option<Pubkey>> {
+    let Some(asset_group) = asset_data.group else {
+        return Ok(None);
+    };
+
+    let group = group.ok_or(ZkNftError::GroupAccountMissing)?;
+    require_keys_eq!(group.key(), asset_group, ZkNftError::GroupMismatch);
+
+    Ok(group.transfer_hook_program)
+}
+
+/// CPIs into the group's transfer hook. The hook program and the extra accounts
+/// it needs, following it, are looked up in the remaining accounts.
+pub fn invoke_transfer_hook<'info>(
+    transfer_hook_program: Pubkey,
+    group: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    recipient: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    asset_id: [u8; 32],
+    sender: Pubkey,
+) -> Result<()> {
+    let hook_program_position = remaining_accounts
+        .iter()
+        .position(|account| account.key() == transfer_hook_program)
+        .ok_or(ZkNftError::TransferHookProgramMissing)?;
+    let additional_accounts = &remaining_accounts[hook_program_position + 1..];
+
+    let instruction = zk_nft_transfer_hook_interface::execute(
+        &transfer_hook_program,
+        group.key,
+        authority.key,
+        recipient.key,
+        asset_id,
+        &sender,
+        &additional_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: false,
+                is_writable: account.is_writable,
+            })
+            .collect::<Vec<AccountMeta>>(),
+    );
+
+    let mut account_infos = vec![
+        group,
+        authority,
+        recipient,
+        remaining_accounts[hook_program_position].clone(),
+    ];
+    account_infos.extend_from_slice(additional_accounts);
+    invoke(&instruction, &account_infos)?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CONTENT_TYPE_LEN;
+use crate::errors::ZkNftError;
+
+/// Checks that `content_type` is a MIME type of the form `type/subtype`,
+/// optionally followed by `; name=value` parameters.
+pub fn validate_content_type(content_type: &str) -> Result<()> {
+    require!(
+        !content_type.is_empty() && content_type.len() <= MAX_CONTENT_TYPE_LEN,
+        ZkNftError::InvalidContentType
+    );
+
+    let mut parts = content_type.split(';');
+    let (media_type, media_subtype) = parts
+        .next()
+        .and_then(|essence| essence.split_once('/'))
+        .ok_or(ZkNftError::InvalidContentType)?;
+    require!(
+        is_token(media_type) && is_token(media_subtype),
+        ZkNftError::InvalidContentType
+    );
+
+    for parameter in parts {
+        let (name, value) = parameter
+            .trim_start_matches(' ')
+            .split_once('=')
+            .ok_or(ZkNftError::InvalidContentType)?;
+        require!(
+            is_token(name) && is_token(value),
+            ZkNftError::InvalidContentType
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns the `type/subtype` part of `content_type`, lowercased.
+pub fn content_type_essence(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+fn is_token(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte))
+}
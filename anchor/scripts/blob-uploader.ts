@@ -1,4 +1,4 @@
-import { Program } from "@coral-xyz/anchor";
+import { IdlTypes, Program } from "@coral-xyz/anchor";
 import { ZkNft } from "../target/types/zk_nft";
 import {
   ComputeBudgetProgram,
@@ -14,26 +14,36 @@ import { chunk } from "lodash";
 
 const PART_LENGTH = 920;
 const TRANSACTION_CHUNK_SIZE = 10;
+const MAX_BLOB_UPLOADERS = 4;
 const UPLOADED_BLOB_BUFFER_START =
   8 + // discriminator
-  32; // authority
+  32 + // authority
+  1 + // content encoding
+  32 * MAX_BLOB_UPLOADERS + // uploaders
+  1 + // finalized
+  32; // hash
+
+type ContentEncoding = IdlTypes<ZkNft>["ContentEncoding"];
 
 export class BlobUploader {
   private readonly program: Program<ZkNft>;
   private readonly blob: Buffer;
   private readonly keypair: Keypair;
   private readonly skipPreflight: boolean;
+  private readonly contentEncoding: ContentEncoding;
 
   constructor(
     program: Program<ZkNft>,
     keypair: Keypair,
     blob: Buffer,
-    skipPreflight: boolean = false
+    skipPreflight: boolean = false,
+    contentEncoding: ContentEncoding = { identity: {} }
   ) {
     this.program = program;
     this.blob = blob;
     this.keypair = keypair;
     this.skipPreflight = skipPreflight;
+    this.contentEncoding = contentEncoding;
   }
 
   async uploadBlob() {
@@ -85,7 +95,7 @@ export class BlobUploader {
 
   private async initBlobUpload(uploadedBlob: PublicKey) {
     const ix = await this.program.methods
-      .initBlobUpload(this.blob.length)
+      .initBlobUpload(this.blob.length, this.contentEncoding)
       .accounts({
         authority: this.keypair.publicKey,
         uploadedBlob,
@@ -0,0 +1,240 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { LightSystemProgram } from "@lightprotocol/stateless.js";
+import { expect } from "chai";
+import {
+  CompressedPda,
+  Types,
+  accountIndex,
+  addressTree,
+  connection,
+  createAsset,
+  expectError,
+  fetchAsset,
+  fetchCompressed,
+  fundedKeypair,
+  keypair,
+  lightAccounts,
+  newAddress,
+  prepare,
+  prepareAsset,
+  program,
+  resourceSeed,
+  send,
+  toAccountMetas,
+} from "./helpers";
+
+const solPoolPda = LightSystemProgram.deriveCompressedSolPda();
+
+function bidSeed(assetId: PublicKey, bidder: PublicKey, bidId: number) {
+  return resourceSeed(
+    Buffer.concat([
+      Buffer.from("bid"),
+      bidder.toBuffer(),
+      new anchor.BN(bidId).toArrayLike(Buffer, "le", 8),
+    ]),
+    assetId
+  );
+}
+
+async function placeBid(
+  assetId: PublicKey,
+  bidder: Keypair,
+  amount: number,
+  bidId = 0
+) {
+  const address = await newAddress(bidSeed(assetId, bidder.publicKey, bidId));
+  const prepared = await prepare([], [address]);
+
+  const ix = await program.methods
+    .placeBid(
+      prepared.proof,
+      Array.from(assetId.toBytes()),
+      new anchor.BN(bidId),
+      new anchor.BN(amount),
+      prepared.newAddresses[0]
+    )
+    .accounts({
+      bidder: bidder.publicKey,
+      solPoolPda,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  await send([ix], [], bidder);
+  return fetchCompressed<Types["Bid"]>("Bid", address.address);
+}
+
+function bidParams(
+  bid: CompressedPda<Types["Bid"]>,
+  input: Types["PackedInputCompressedPda"],
+  addressTreeIndex: number
+): Types["BidParams"] {
+  return {
+    data: bid.data,
+    input,
+    addressMerkleTreeAccountIndex: addressTreeIndex,
+  };
+}
+
+async function cancelBid(bid: CompressedPda<Types["Bid"]>, bidder: Keypair) {
+  const prepared = await prepare([bid]);
+  const addressTreeIndex = accountIndex(
+    prepared.remainingAccounts,
+    addressTree
+  );
+
+  const ix = await program.methods
+    .cancelBid(
+      prepared.proof,
+      bidParams(bid, prepared.inputs[0], addressTreeIndex)
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      bidder: bidder.publicKey,
+      solPoolPda,
+      decompressionRecipient: bidder.publicKey,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix], [bidder]);
+}
+
+async function acceptBidIx(
+  assetId: PublicKey,
+  owner: Keypair,
+  bid: CompressedPda<Types["Bid"]>,
+  bidder: PublicKey = bid.data.bidder
+): Promise<TransactionInstruction> {
+  const prepared = await prepareAsset(assetId, { inputs: [bid] });
+
+  return program.methods
+    .acceptBid(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput,
+      prepared.assetDataParams,
+      bidParams(bid, prepared.inputs[2], prepared.addressTreeIndex),
+      null
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: owner.publicKey,
+      bidder,
+      group: null,
+      recipientPolicy: null,
+      solPoolPda,
+      decompressionRecipient: owner.publicKey,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+}
+
+describe("bids", () => {
+  const amount = LAMPORTS_PER_SOL / 4;
+  let owner: Keypair;
+  let bidder: Keypair;
+
+  before(async () => {
+    owner = await fundedKeypair();
+    bidder = await fundedKeypair(2 * LAMPORTS_PER_SOL);
+  });
+
+  it("Escrows the bid amount in the bid account", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const bid = await placeBid(assetId, bidder, amount);
+
+    expect(bid.lamports.toNumber()).to.equal(amount);
+    expect(bid.data.amount.toNumber()).to.equal(amount);
+    expect(bid.data.bidder.toBase58()).to.equal(bidder.publicKey.toBase58());
+    expect(bid.data.assetId).to.deep.equal(Array.from(assetId.toBytes()));
+  });
+
+  it("Refunds the bidder when the bid is cancelled", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const bid = await placeBid(assetId, bidder, amount);
+    const balance = await connection.getBalance(bidder.publicKey);
+
+    const intruder = await fundedKeypair();
+    await expectError(cancelBid(bid, intruder), "InvalidAuthority");
+
+    await cancelBid(bid, bidder);
+    expect(await connection.getBalance(bidder.publicKey)).to.equal(
+      balance + amount
+    );
+    expect(await fetchCompressed("Bid", bid.address)).to.equal(null);
+  });
+
+  it("Sells the asset to the bidder when accepted", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const bid = await placeBid(assetId, bidder, amount);
+    const balance = await connection.getBalance(owner.publicKey);
+
+    const ix = await acceptBidIx(assetId, owner, bid);
+    await send([ix], [owner]);
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(
+      bidder.publicKey.toBase58()
+    );
+    expect(await connection.getBalance(owner.publicKey)).to.equal(
+      balance + amount
+    );
+    expect(await fetchCompressed("Bid", bid.address)).to.equal(null);
+  });
+
+  it("Lets the bidder raise a bid under a new id", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const bid = await placeBid(assetId, bidder, amount);
+    const raised = await placeBid(assetId, bidder, 2 * amount, 1);
+    expect(raised.data.bidId.toNumber()).to.equal(1);
+    expect(raised.address.equals(bid.address)).to.be.false;
+
+    await cancelBid(bid, bidder);
+    const balance = await connection.getBalance(owner.publicKey);
+    const ix = await acceptBidIx(assetId, owner, raised);
+    await send([ix], [owner]);
+    expect(await connection.getBalance(owner.publicKey)).to.equal(
+      balance + 2 * amount
+    );
+  });
+
+  it("Rejects bids for another asset or bidder", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const otherAssetId = await createAsset({ recipient: owner.publicKey });
+    const bid = await placeBid(otherAssetId, bidder, amount);
+
+    const wrongAsset = await acceptBidIx(assetId, owner, bid);
+    await expectError(send([wrongAsset], [owner]), "BidMismatch");
+
+    const wrongBidder = await acceptBidIx(
+      otherAssetId,
+      owner,
+      bid,
+      Keypair.generate().publicKey
+    );
+    await expectError(send([wrongBidder], [owner]), "BidMismatch");
+  });
+
+  it("Only lets the owner accept a bid", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const bid = await placeBid(assetId, bidder, amount);
+
+    const intruder = await fundedKeypair();
+    const ix = await acceptBidIx(assetId, intruder, bid);
+    await expectError(send([ix], [intruder]), "InvalidAuthority");
+  });
+
+  it("Rejects empty bids", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    await expectError(placeBid(assetId, bidder, 0), "InvalidPrice");
+  });
+});
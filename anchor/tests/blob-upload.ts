@@ -0,0 +1,246 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { sha256 } from "@noble/hashes/sha256";
+import {
+  blobData,
+  createAsset,
+  createAssetIx,
+  expectError,
+  fetchCpiEvents,
+  fetchInnerInstructions,
+  fetchCompressed,
+  fundedKeypair,
+  keypair,
+  logBlob,
+  program,
+  resourceAddress,
+  send,
+  uploadBlob,
+} from "./helpers";
+
+const BLOB_CHUNK_HEADER_LEN = 32 + 4 + 4 + 32;
+
+async function uploadBlobIx(
+  uploadedBlob: PublicKey,
+  bytes: Buffer,
+  authority: Keypair
+) {
+  return program.methods
+    .uploadBlob(0, bytes)
+    .accounts({ uploadedBlob, authority: authority.publicKey })
+    .instruction();
+}
+
+async function setBlobUploaders(
+  uploadedBlob: PublicKey,
+  uploaders: PublicKey[],
+  authority: Keypair = keypair
+) {
+  const ix = await program.methods
+    .setBlobUploaders(uploaders)
+    .accounts({ uploadedBlob, authority: authority.publicKey })
+    .instruction();
+  return send([ix], [authority]);
+}
+
+async function setBlobAuthority(
+  uploadedBlob: PublicKey,
+  newAuthority: PublicKey,
+  authority: Keypair = keypair
+) {
+  const ix = await program.methods
+    .setBlobAuthority(newAuthority)
+    .accounts({ uploadedBlob, authority: authority.publicKey })
+    .instruction();
+  return send([ix], [authority]);
+}
+
+describe("blob upload", () => {
+  const bytes = Buffer.from(JSON.stringify({ name: "zkNFT #1" }));
+  let uploadedBlob: PublicKey;
+  let signature: string;
+
+  it("Frames logged chunks with a header", async () => {
+    uploadedBlob = await uploadBlob(bytes, { finalize: false });
+    signature = await logBlob(uploadedBlob);
+
+    const noopProgram = new PublicKey(
+      "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+    );
+    const chunks = (await fetchInnerInstructions(signature)).filter(
+      ({ programId }) => programId.equals(noopProgram)
+    );
+    expect(chunks).to.have.length(1);
+
+    const header = chunks[0].data.subarray(0, BLOB_CHUNK_HEADER_LEN);
+    expect(new PublicKey(header.subarray(0, 32)).toBase58()).to.equal(
+      uploadedBlob.toBase58()
+    );
+    expect(header.readUInt32LE(32)).to.equal(0);
+    expect(header.readUInt32LE(36)).to.equal(1);
+    expect(Array.from(header.subarray(40))).to.deep.equal(
+      Array.from(sha256(bytes))
+    );
+    expect(
+      chunks[0].data.subarray(BLOB_CHUNK_HEADER_LEN).equals(bytes)
+    ).to.equal(true);
+  });
+
+  it("Emits a blob logged event and finalizes the buffer", async () => {
+    const events = await fetchCpiEvents(signature);
+    const event = events.find(({ name }) => name === "BlobLoggedEvent");
+    expect(event.data.uploadedBlob.toBase58()).to.equal(
+      uploadedBlob.toBase58()
+    );
+    expect(event.data.totalChunks).to.equal(1);
+    expect(event.data.totalBytes).to.equal(bytes.length);
+    expect(event.data.hash).to.deep.equal(Array.from(sha256(bytes)));
+
+    const uploadedBlobData = await program.account.uploadedBlob.fetch(
+      uploadedBlob
+    );
+    expect(uploadedBlobData.finalized).to.equal(true);
+    expect(uploadedBlobData.hash).to.deep.equal(Array.from(sha256(bytes)));
+  });
+
+  it("Rejects writes to a finalized buffer", async () => {
+    await expectError(logBlob(uploadedBlob), "UploadedBlobFinalized");
+
+    const ix = await uploadBlobIx(
+      uploadedBlob,
+      Buffer.from("overwrite"),
+      keypair
+    );
+    await expectError(send([ix]), "UploadedBlobFinalized");
+  });
+});
+
+describe("blob upload delegation", () => {
+  const bytes = Buffer.from("delegated upload");
+  let uploader: Keypair;
+  let uploadedBlob: PublicKey;
+
+  before(async () => {
+    uploader = await fundedKeypair();
+    uploadedBlob = await uploadBlob(Buffer.alloc(bytes.length), {
+      finalize: false,
+    });
+  });
+
+  it("Lets delegated uploaders write and log the buffer", async () => {
+    await setBlobUploaders(uploadedBlob, [uploader.publicKey]);
+
+    await send([await uploadBlobIx(uploadedBlob, bytes, uploader)], [uploader]);
+    await logBlob(uploadedBlob, uploader);
+
+    const uploadedBlobData = await program.account.uploadedBlob.fetch(
+      uploadedBlob
+    );
+    expect(uploadedBlobData.finalized).to.equal(true);
+    expect(uploadedBlobData.hash).to.deep.equal(Array.from(sha256(bytes)));
+  });
+
+  it("Rejects writes from anyone else", async () => {
+    const stranger = await fundedKeypair();
+    const buffer = await uploadBlob(Buffer.alloc(4), { finalize: false });
+
+    const ix = await uploadBlobIx(buffer, Buffer.from("evil"), stranger);
+    await expectError(send([ix], [stranger]), "InvalidAuthority");
+  });
+
+  it("Only lets the authority manage uploaders", async () => {
+    const buffer = await uploadBlob(Buffer.alloc(4), { finalize: false });
+    await setBlobUploaders(buffer, [uploader.publicKey]);
+
+    await expectError(
+      setBlobUploaders(buffer, [uploader.publicKey], uploader),
+      "ConstraintHasOne"
+    );
+    await expectError(
+      setBlobUploaders(
+        buffer,
+        Array.from({ length: 5 }, () => Keypair.generate().publicKey)
+      ),
+      "TooManyUploaders"
+    );
+  });
+
+  it("Revokes uploaders when handing the buffer over", async () => {
+    const newAuthority = await fundedKeypair();
+    const buffer = await uploadBlob(Buffer.alloc(4), { finalize: false });
+    await setBlobUploaders(buffer, [uploader.publicKey]);
+    await setBlobAuthority(buffer, newAuthority.publicKey);
+
+    const uploadedBlobData = await program.account.uploadedBlob.fetch(buffer);
+    expect(uploadedBlobData.authority.toBase58()).to.equal(
+      newAuthority.publicKey.toBase58()
+    );
+    expect(
+      uploadedBlobData.uploaders.every((key) => key.equals(PublicKey.default))
+    ).to.equal(true);
+
+    for (const previous of [uploader, keypair]) {
+      const ix = await uploadBlobIx(buffer, Buffer.from("late"), previous);
+      await expectError(send([ix], [previous]), "InvalidAuthority");
+    }
+    await send(
+      [await uploadBlobIx(buffer, Buffer.from("mine"), newAuthority)],
+      [newAuthority]
+    );
+  });
+});
+
+describe("uploaded blob links", () => {
+  const bytes = Buffer.from(JSON.stringify({ name: "zkNFT #2" }));
+  const hash = Array.from(sha256(bytes));
+
+  it("Mints a blob linked to its finalized buffer", async () => {
+    const uploadedBlob = await uploadBlob(bytes);
+    const assetId = await createAsset({
+      blob: blobData({ uploadedBlob, hash }),
+      uploadedBlob,
+    });
+
+    const blob = await fetchCompressed(
+      "Blob",
+      await resourceAddress("blob", assetId)
+    );
+    expect(blob.data.uploadedBlob.toBase58()).to.equal(uploadedBlob.toBase58());
+    expect(blob.data.hash).to.deep.equal(hash);
+  });
+
+  it("Rejects buffers that are not finalized", async () => {
+    const uploadedBlob = await uploadBlob(bytes, { finalize: false });
+    const { ix } = await createAssetIx({
+      blob: blobData({ uploadedBlob, hash }),
+      uploadedBlob,
+    });
+    await expectError(send([ix]), "UploadedBlobNotFinalized");
+  });
+
+  it("Rejects blobs that do not match the buffer", async () => {
+    const uploadedBlob = await uploadBlob(bytes);
+
+    const { ix: missingBuffer } = await createAssetIx({
+      blob: blobData({ uploadedBlob, hash }),
+    });
+    await expectError(send([missingBuffer]), "UploadedBlobMismatch");
+
+    const { ix: wrongHash } = await createAssetIx({
+      blob: blobData({ uploadedBlob, hash: Array.from(sha256(hash)) }),
+      uploadedBlob,
+    });
+    await expectError(send([wrongHash]), "UploadedBlobMismatch");
+  });
+
+  it("Requires the buffer authority to approve the link", async () => {
+    const authority = await fundedKeypair();
+    const uploadedBlob = await uploadBlob(bytes, { authority });
+
+    const { ix } = await createAssetIx({
+      blob: blobData({ uploadedBlob, hash }),
+      uploadedBlob,
+    });
+    await expectError(send([ix]), "InvalidUploadedBlobAuthority");
+  });
+});
@@ -0,0 +1,170 @@
+import {
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createMint,
+  mintTo,
+} from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  connection,
+  createGroup,
+  expectError,
+  fundedKeypair,
+  keypair,
+  lightAccounts,
+  newAddress,
+  prepare,
+  program,
+  resourceSeed,
+  send,
+  toAccountMetas,
+} from "./helpers";
+
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+);
+
+function metadataAddress(mint: PublicKey, ...extra: Buffer[]) {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("metadata"),
+      TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+      mint.toBuffer(),
+      ...extra,
+    ],
+    TOKEN_METADATA_PROGRAM_ID
+  )[0];
+}
+
+async function compressFromSplIx(
+  owner: Keypair,
+  mint: PublicKey,
+  {
+    tokenAccount,
+    metadata = metadataAddress(mint),
+  }: { tokenAccount: PublicKey; metadata?: PublicKey }
+) {
+  const baseData = await newAddress(resourceSeed("spl_mint", mint));
+  const assetData = await newAddress(
+    resourceSeed("asset_data", baseData.address)
+  );
+  const prepared = await prepare([], [baseData, assetData]);
+  const attributesPrepared = await prepare(
+    [],
+    [await newAddress(resourceSeed("attributes", baseData.address))],
+    prepared.remainingAccounts
+  );
+
+  return program.methods
+    .compressFromSpl(
+      prepared.proof,
+      prepared.newAddresses[0],
+      attributesPrepared.proof
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: owner.publicKey,
+      mint,
+      tokenAccount,
+      metadata,
+      edition: metadataAddress(mint, Buffer.from("edition")),
+      collectionMetadata: null,
+      group: null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(attributesPrepared.remainingAccounts))
+    .instruction();
+}
+
+async function setGroupCollection(
+  group: PublicKey,
+  collection: PublicKey | null,
+  collectionMetadata: PublicKey | null
+) {
+  const ix = await program.methods
+    .setGroupCollection(collection)
+    .accounts({
+      groupAuthority: keypair.publicKey,
+      group,
+      collectionAuthority: null,
+      collectionMetadata,
+    })
+    .instruction();
+  return send([ix]);
+}
+
+// Importing a full Metaplex NFT needs the Token Metadata program on the test
+// validator. These cover the account checks made before it is invoked.
+describe("compress from spl", () => {
+  let owner: Keypair;
+  let mint: PublicKey;
+  let tokenAccount: PublicKey;
+
+  before(async () => {
+    owner = await fundedKeypair();
+    mint = await createMint(connection, keypair, keypair.publicKey, null, 0);
+    tokenAccount = await createAccount(
+      connection,
+      keypair,
+      mint,
+      owner.publicKey
+    );
+    await mintTo(connection, keypair, mint, tokenAccount, keypair, 1);
+  });
+
+  it("Requires the owner's token account for the mint", async () => {
+    const stranger = await fundedKeypair();
+    const strangerIx = await compressFromSplIx(stranger, mint, {
+      tokenAccount,
+    });
+    await expectError(send([strangerIx], [stranger]), "ConstraintTokenOwner");
+
+    const otherMint = await createMint(
+      connection,
+      keypair,
+      keypair.publicKey,
+      null,
+      0
+    );
+    const otherMintIx = await compressFromSplIx(owner, otherMint, {
+      tokenAccount,
+    });
+    await expectError(send([otherMintIx], [owner]), "ConstraintTokenMint");
+  });
+
+  it("Rejects metadata not owned by Token Metadata", async () => {
+    const ix = await compressFromSplIx(owner, mint, {
+      tokenAccount,
+      metadata: tokenAccount,
+    });
+    await expectError(send([ix], [owner]), "AccountOwnedByWrongProgram");
+  });
+
+  describe("group collections", () => {
+    let group: PublicKey;
+
+    before(async () => {
+      group = await createGroup();
+    });
+
+    it("Requires the collection's metadata", async () => {
+      await expectError(
+        setGroupCollection(group, mint, null),
+        "InvalidMetadata"
+      );
+      await expectError(
+        setGroupCollection(group, mint, tokenAccount),
+        "AccountOwnedByWrongProgram"
+      );
+    });
+
+    it("Clears the collection without its metadata", async () => {
+      await setGroupCollection(group, null, null);
+      const { collection } = await program.account.group.fetch(group);
+      expect(collection).to.be.null;
+    });
+  });
+});
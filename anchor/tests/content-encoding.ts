@@ -0,0 +1,53 @@
+import { expect } from "chai";
+import {
+  blobData,
+  createAsset,
+  createAssetIx,
+  expectError,
+  fetchCompressed,
+  program,
+  resourceAddress,
+  send,
+  uploadBlob,
+} from "./helpers";
+
+describe("content encoding", () => {
+  it("Stores the blob's content encoding", async () => {
+    const assetId = await createAsset({
+      blob: blobData({ contentEncoding: { gzip: {} } }),
+    });
+
+    const blob = await fetchCompressed(
+      "Blob",
+      await resourceAddress("blob", assetId)
+    );
+    expect(blob.data.contentEncoding).to.deep.equal({ gzip: {} });
+  });
+
+  it("Records the encoding of uploaded blob buffers", async () => {
+    const uploadedBlob = await uploadBlob(Buffer.from("zstd frame"), {
+      contentEncoding: { zstd: {} },
+    });
+
+    const uploadedBlobData = await program.account.uploadedBlob.fetch(
+      uploadedBlob
+    );
+    expect(uploadedBlobData.contentEncoding).to.deep.equal({ zstd: {} });
+    expect(uploadedBlobData.finalized).to.equal(true);
+  });
+
+  it("Rejects a blob whose encoding differs from its uploaded buffer", async () => {
+    const uploadedBlob = await uploadBlob(Buffer.from("{}"));
+    const { hash } = await program.account.uploadedBlob.fetch(uploadedBlob);
+
+    const { ix } = await createAssetIx({
+      blob: blobData({
+        contentEncoding: { brotli: {} },
+        uploadedBlob,
+        hash,
+      }),
+      uploadedBlob,
+    });
+    await expectError(send([ix]), "UploadedBlobMismatch");
+  });
+});
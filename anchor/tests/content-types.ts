@@ -0,0 +1,98 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  blobData,
+  createAsset,
+  createAssetIx,
+  createGroup,
+  expectError,
+  fetchCompressed,
+  fundedKeypair,
+  keypair,
+  program,
+  resourceAddress,
+  send,
+} from "./helpers";
+
+async function setGroupContentTypes(
+  group: PublicKey,
+  contentTypes: string[],
+  groupAuthority: Keypair = keypair
+) {
+  const ix = await program.methods
+    .setGroupContentTypes(contentTypes)
+    .accounts({ groupAuthority: groupAuthority.publicKey, group })
+    .instruction();
+  return send([ix], [groupAuthority]);
+}
+
+describe("content types", () => {
+  let group: PublicKey;
+
+  before(async () => {
+    group = await createGroup();
+  });
+
+  it("Accepts content types with parameters", async () => {
+    const assetId = await createAsset({
+      blob: blobData({ contentType: "text/plain; charset=utf-8" }),
+    });
+
+    const blob = await fetchCompressed(
+      "Blob",
+      await resourceAddress("blob", assetId)
+    );
+    expect(blob.data.contentType).to.equal("text/plain; charset=utf-8");
+  });
+
+  it("Rejects malformed content types", async () => {
+    for (const contentType of ["json", "text/", "text/plain; charset"]) {
+      const { ix } = await createAssetIx({ blob: blobData({ contentType }) });
+      await expectError(send([ix]), "InvalidContentType");
+    }
+  });
+
+  it("Stores the group allowlist as lowercase essences", async () => {
+    await setGroupContentTypes(group, [
+      "image/png",
+      "Application/JSON; charset=utf-8",
+    ]);
+
+    const groupData = await program.account.group.fetch(group);
+    expect(groupData.allowedContentTypes).to.deep.equal([
+      "image/png",
+      "application/json",
+    ]);
+  });
+
+  it("Only mints allowed content types into the group", async () => {
+    await createAsset({
+      group,
+      blob: blobData({ contentType: "application/json; charset=utf-8" }),
+    });
+
+    const { ix } = await createAssetIx({
+      group,
+      blob: blobData({ contentType: "text/html" }),
+    });
+    await expectError(send([ix]), "ContentTypeNotAllowed");
+  });
+
+  it("Rejects too many allowed content types", async () => {
+    await expectError(
+      setGroupContentTypes(
+        group,
+        Array.from({ length: 9 }, (_, i) => `image/x-${i}`)
+      ),
+      "TooManyContentTypes"
+    );
+  });
+
+  it("Only lets the group authority set the allowlist", async () => {
+    const intruder = await fundedKeypair();
+    await expectError(
+      setGroupContentTypes(group, [], intruder),
+      "InvalidAuthority"
+    );
+  });
+});
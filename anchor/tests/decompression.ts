@@ -0,0 +1,251 @@
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotent,
+  getAssociatedTokenAddressSync,
+  getTokenMetadata,
+  transferChecked,
+} from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  Types,
+  connection,
+  createAsset,
+  createMasterEdition,
+  expectError,
+  fetchAsset,
+  fetchCompressed,
+  fundedKeypair,
+  keypair,
+  lightAccounts,
+  prepareAsset,
+  printEdition,
+  program,
+  resourceAddress,
+  send,
+  toAccountMetas,
+  transfer,
+} from "./helpers";
+
+function decompressedMint(assetId: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("decompressed_mint"), assetId.toBuffer()],
+    program.programId
+  )[0];
+}
+
+function tokenAccount(assetId: PublicKey, holder: PublicKey) {
+  return getAssociatedTokenAddressSync(
+    decompressedMint(assetId),
+    holder,
+    false,
+    TOKEN_2022_PROGRAM_ID
+  );
+}
+
+async function decompress(assetId: PublicKey, owner: Keypair) {
+  // prints carry the attributes of their master
+  const { assetData } = await fetchAsset(assetId);
+  const parent = assetData.data.edition?.print?.parent;
+  const attributes = assetData.data.hasAttributes
+    ? await fetchCompressed<Types["Attributes"]>(
+        "Attributes",
+        await resourceAddress(
+          "attributes",
+          parent ? new PublicKey(parent) : assetId
+        )
+      )
+    : null;
+  const prepared = await prepareAsset(assetId, {
+    inputs: attributes ? [attributes] : [],
+  });
+
+  const ix = await program.methods
+    .decompress(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput,
+      prepared.assetDataParams,
+      attributes
+        ? {
+            data: attributes.data,
+            input: prepared.inputs[2],
+            addressMerkleTreeAccountIndex: prepared.addressTreeIndex,
+          }
+        : null
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: owner.publicKey,
+      group: assetData.data.group,
+      mint: decompressedMint(assetId),
+      tokenAccount: tokenAccount(assetId, owner.publicKey),
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix], [owner]);
+}
+
+async function compress(assetId: PublicKey, holder: Keypair) {
+  const prepared = await prepareAsset(assetId, { withAssetData: false });
+
+  const ix = await program.methods
+    .compress(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: holder.publicKey,
+      mint: decompressedMint(assetId),
+      tokenAccount: tokenAccount(assetId, holder.publicKey),
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix], [holder]);
+}
+
+async function tokenBalance(assetId: PublicKey, holder: PublicKey) {
+  const { value } = await connection.getTokenAccountBalance(
+    tokenAccount(assetId, holder)
+  );
+  return Number(value.amount);
+}
+
+describe("decompression", () => {
+  let owner: Keypair;
+
+  before(async () => {
+    owner = await fundedKeypair();
+  });
+
+  it("Round trips an asset through a Token-2022 mint", async () => {
+    const assetId = await createAsset({
+      recipient: owner.publicKey,
+      attributes: [{ traitType: "background", value: "blue" }],
+    });
+
+    await decompress(assetId, owner);
+    let { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.state).to.deep.equal({ decompressed: {} });
+    expect(await tokenBalance(assetId, owner.publicKey)).to.equal(1);
+    const mint = await connection.getAccountInfo(decompressedMint(assetId));
+    expect(mint.owner.toBase58()).to.equal(TOKEN_2022_PROGRAM_ID.toBase58());
+    const metadata = await getTokenMetadata(
+      connection,
+      decompressedMint(assetId)
+    );
+    expect(metadata.name).to.equal("");
+    expect(metadata.uri).to.equal("");
+    expect(metadata.additionalMetadata).to.deep.equal([
+      ["asset_id", assetId.toBase58()],
+      ["background", "blue"],
+    ]);
+
+    await compress(assetId, owner);
+    ({ baseData } = await fetchAsset(assetId));
+    expect(baseData.data.state).to.deep.equal({ unlocked: {} });
+    expect(baseData.data.owner.toBase58()).to.equal(owner.publicKey.toBase58());
+    expect(await tokenBalance(assetId, owner.publicKey)).to.equal(0);
+
+    // the mint is reused on later decompressions
+    await decompress(assetId, owner);
+    expect(await tokenBalance(assetId, owner.publicKey)).to.equal(1);
+  });
+
+  it("Hands the asset to whoever holds the token", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    await decompress(assetId, owner);
+
+    const holder = await fundedKeypair();
+    const destination = await createAssociatedTokenAccountIdempotent(
+      connection,
+      keypair,
+      decompressedMint(assetId),
+      holder.publicKey,
+      {},
+      TOKEN_2022_PROGRAM_ID
+    );
+    await transferChecked(
+      connection,
+      keypair,
+      tokenAccount(assetId, owner.publicKey),
+      decompressedMint(assetId),
+      destination,
+      owner,
+      1,
+      0,
+      [],
+      {},
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await compress(assetId, holder);
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(
+      holder.publicKey.toBase58()
+    );
+  });
+
+  it("Decompresses prints with their master's attributes", async () => {
+    const masterId = await createAsset({
+      attributes: [{ traitType: "artist", value: "anon" }],
+    });
+    await createMasterEdition(masterId, null);
+    const printId = await printEdition(masterId, owner.publicKey);
+
+    await decompress(printId, owner);
+    expect(await tokenBalance(printId, owner.publicKey)).to.equal(1);
+    const metadata = await getTokenMetadata(
+      connection,
+      decompressedMint(printId)
+    );
+    expect(metadata.additionalMetadata).to.deep.equal([
+      ["asset_id", printId.toBase58()],
+      ["artist", "anon"],
+    ]);
+  });
+
+  it("Freezes the compressed asset while decompressed", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    await decompress(assetId, owner);
+
+    await expectError(
+      transfer(assetId, {
+        authority: owner,
+        recipient: Keypair.generate().publicKey,
+      }),
+      "AssetDecompressed"
+    );
+
+    await compress(assetId, owner);
+    await expectError(compress(assetId, owner), "AssetNotDecompressed");
+  });
+
+  it("Keeps assets with enforced royalties compressed", async () => {
+    const assetId = await createAsset({
+      recipient: owner.publicKey,
+      royalties: {
+        basisPoints: 500,
+        enforced: true,
+        creators: [{ address: keypair.publicKey, share: 100 }],
+      },
+    });
+    await expectError(decompress(assetId, owner), "DecompressionRestricted");
+  });
+
+  it("Only lets the owner decompress", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const intruder = await fundedKeypair();
+    await expectError(decompress(assetId, intruder), "InvalidAuthority");
+  });
+});
@@ -0,0 +1,240 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  Types,
+  createAsset,
+  expectError,
+  fetchAsset,
+  fundedKeypair,
+  keypair,
+  lightAccounts,
+  lock,
+  now,
+  prepareAsset,
+  program,
+  send,
+  toAccountMetas,
+  transfer,
+  unlock,
+  waitUntil,
+} from "./helpers";
+
+async function approve(
+  assetId: PublicKey,
+  owner: Keypair,
+  delegate: PublicKey,
+  role: Types["DelegateRole"],
+  expiry: number | null
+) {
+  const prepared = await prepareAsset(assetId, { withAssetData: false });
+  const ix = await program.methods
+    .approve(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput,
+      role,
+      expiry === null ? null : new anchor.BN(expiry)
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: owner.publicKey,
+      delegate,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix], [owner]);
+}
+
+async function revoke(assetId: PublicKey, owner: Keypair) {
+  const prepared = await prepareAsset(assetId, { withAssetData: false });
+  const ix = await program.methods
+    .revoke(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: owner.publicKey,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix], [owner]);
+}
+
+async function rentAsset(
+  assetId: PublicKey,
+  owner: Keypair,
+  renter: PublicKey,
+  expiry: number
+) {
+  const prepared = await prepareAsset(assetId, { withAssetData: false });
+  const ix = await program.methods
+    .rentAsset(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput,
+      new anchor.BN(expiry)
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: owner.publicKey,
+      renter,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix], [owner]);
+}
+
+describe("delegation", () => {
+  let owner: Keypair;
+  let delegate: Keypair;
+
+  before(async () => {
+    owner = await fundedKeypair();
+    delegate = await fundedKeypair();
+  });
+
+  it("Lets a transfer delegate move the asset", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    await approve(
+      assetId,
+      owner,
+      delegate.publicKey,
+      { transfer: {} },
+      (await now()) + 3600
+    );
+
+    let { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.delegate.toBase58()).to.equal(
+      delegate.publicKey.toBase58()
+    );
+    expect(baseData.data.delegateRole).to.deep.equal({ transfer: {} });
+
+    const recipient = Keypair.generate().publicKey;
+    await transfer(assetId, { authority: delegate, recipient });
+    ({ baseData } = await fetchAsset(assetId));
+    expect(baseData.data.owner.toBase58()).to.equal(recipient.toBase58());
+    expect(baseData.data.delegate).to.equal(null);
+  });
+
+  it("Limits the delegate to its role", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    await approve(assetId, owner, delegate.publicKey, { lock: {} }, null);
+
+    await expectError(
+      transfer(assetId, {
+        authority: delegate,
+        recipient: delegate.publicKey,
+      }),
+      "InvalidAuthority"
+    );
+  });
+
+  it("Drops the delegate when the owner transfers the asset", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    await approve(assetId, owner, delegate.publicKey, { all: {} }, null);
+    await transfer(assetId, {
+      authority: owner,
+      recipient: Keypair.generate().publicKey,
+    });
+
+    await expectError(
+      transfer(assetId, {
+        authority: delegate,
+        recipient: delegate.publicKey,
+      }),
+      "InvalidAuthority"
+    );
+  });
+
+  it("Drops revoked delegates", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    await approve(assetId, owner, delegate.publicKey, { all: {} }, null);
+    await revoke(assetId, owner);
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.delegate).to.equal(null);
+    await expectError(
+      transfer(assetId, {
+        authority: delegate,
+        recipient: delegate.publicKey,
+      }),
+      "InvalidAuthority"
+    );
+  });
+
+  it("Rejects expiries in the past", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const past = (await now()) - 60;
+
+    await expectError(
+      approve(assetId, owner, delegate.publicKey, { all: {} }, past),
+      "InvalidDelegateExpiry"
+    );
+    await expectError(
+      rentAsset(assetId, owner, delegate.publicKey, past),
+      "InvalidDelegateExpiry"
+    );
+  });
+
+  it("Ends delegate privileges and locks at expiry", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const expiry = (await now()) + 5;
+    await approve(assetId, owner, delegate.publicKey, { all: {} }, expiry);
+    await lock(assetId, { authority: delegate });
+
+    let { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.state).to.deep.equal({ lockedByDelegate: {} });
+    await expectError(
+      unlock(assetId, { authority: owner }),
+      "InvalidAuthority"
+    );
+
+    await waitUntil(expiry);
+    await expectError(
+      transfer(assetId, {
+        authority: delegate,
+        recipient: delegate.publicKey,
+      }),
+      "InvalidAuthority"
+    );
+
+    await unlock(assetId, { authority: owner });
+    ({ baseData } = await fetchAsset(assetId));
+    expect(baseData.data.state).to.deep.equal({ unlocked: {} });
+    expect(baseData.data.delegate).to.equal(null);
+  });
+
+  it("Keeps a rented asset in place until the rental ends", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const expiry = (await now()) + 5;
+    await rentAsset(assetId, owner, delegate.publicKey, expiry);
+
+    let { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.state).to.deep.equal({ rented: {} });
+    expect(baseData.data.delegate.toBase58()).to.equal(
+      delegate.publicKey.toBase58()
+    );
+    expect(baseData.data.delegateRole).to.deep.equal({ use: {} });
+    for (const authority of [owner, delegate]) {
+      await expectError(
+        transfer(assetId, { authority, recipient: authority.publicKey }),
+        "AssetIsLocked"
+      );
+    }
+
+    await waitUntil(expiry);
+    await unlock(assetId, { authority: owner });
+    ({ baseData } = await fetchAsset(assetId));
+    expect(baseData.data.state).to.deep.equal({ unlocked: {} });
+    expect(baseData.data.delegate).to.equal(null);
+  });
+});
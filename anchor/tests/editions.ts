@@ -0,0 +1,134 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  blobData,
+  burn,
+  createAsset,
+  createGroup,
+  createMasterEdition,
+  expectError,
+  fetchAsset,
+  fundedKeypair,
+  keypair,
+  printEdition,
+  program,
+} from "./helpers";
+
+describe("editions", () => {
+  let masterId: PublicKey;
+  let printOwner: Keypair;
+  const prints: PublicKey[] = [];
+
+  before(async () => {
+    printOwner = await fundedKeypair();
+    masterId = await createAsset({
+      blob: blobData(),
+      attributes: [{ traitType: "artist", value: "anon" }],
+    });
+  });
+
+  it("Turns an asset into a master edition", async () => {
+    await createMasterEdition(masterId, 2);
+
+    const { assetData } = await fetchAsset(masterId);
+    expect(assetData.data.edition.master.supply.toNumber()).to.equal(0);
+    expect(assetData.data.edition.master.maxSupply.toNumber()).to.equal(2);
+    await expectError(createMasterEdition(masterId, null), "AssetHasEdition");
+  });
+
+  it("Prints numbered editions of the master", async () => {
+    for (let i = 0; i < 2; i++) {
+      prints.push(await printEdition(masterId, printOwner.publicKey));
+    }
+
+    for (const [i, printId] of prints.entries()) {
+      const { assetData } = await fetchAsset(printId);
+      expect(assetData.data.edition.print.number.toNumber()).to.equal(i + 1);
+      expect(assetData.data.edition.print.parent).to.deep.equal(
+        Array.from(masterId.toBytes())
+      );
+      expect(assetData.data.hasBlob).to.equal(true);
+    }
+    const { assetData } = await fetchAsset(masterId);
+    expect(assetData.data.edition.master.supply.toNumber()).to.equal(2);
+  });
+
+  it("Stops printing at the max supply", async () => {
+    await expectError(
+      printEdition(masterId, keypair.publicKey),
+      "EditionSupplyExceeded"
+    );
+  });
+
+  it("Only prints from master editions", async () => {
+    for (const assetId of [await createAsset(), prints[0]]) {
+      await expectError(
+        printEdition(assetId, keypair.publicKey),
+        "NotMasterEdition"
+      );
+    }
+  });
+
+  it("Only lets the asset authority create a master", async () => {
+    const intruder = await fundedKeypair();
+    await expectError(
+      createMasterEdition(await createAsset(), null, intruder),
+      "InvalidAuthority"
+    );
+
+    // the owner cannot print an asset without an authority
+    const assetId = await createAsset({ authority: null });
+    await expectError(createMasterEdition(assetId, null), "InvalidAuthority");
+  });
+
+  it("Requires the group authority to print grouped masters", async () => {
+    const group = await createGroup();
+    const groupedMasterId = await createAsset({ group });
+    await createMasterEdition(groupedMasterId, null);
+
+    await expectError(
+      printEdition(groupedMasterId, printOwner.publicKey, {
+        groupAuthority: null,
+      }),
+      "GroupAuthorityMissing"
+    );
+    const intruder = await fundedKeypair();
+    await expectError(
+      printEdition(groupedMasterId, printOwner.publicKey, {
+        groupAuthority: intruder,
+      }),
+      "InvalidAuthority"
+    );
+
+    const printId = await printEdition(groupedMasterId, printOwner.publicKey);
+    const { assetData } = await fetchAsset(printId);
+    expect(assetData.data.group.equals(group)).to.be.true;
+    expect(assetData.data.verified).to.equal(true);
+    const { size } = await program.account.group.fetch(group);
+    expect(size.toNumber()).to.equal(2);
+  });
+
+  it("Only lets the master's owner print", async () => {
+    const openMasterId = await createAsset();
+    await createMasterEdition(openMasterId, null);
+
+    const intruder = await fundedKeypair();
+    await expectError(
+      printEdition(openMasterId, intruder.publicKey, { owner: intruder }),
+      "InvalidAuthority"
+    );
+  });
+
+  it("Burns a print without the master's blob or attributes", async () => {
+    await burn(prints[0], { authority: printOwner });
+
+    const { baseData } = await fetchAsset(prints[0]);
+    expect(baseData).to.equal(null);
+    const master = await fetchAsset(masterId);
+    expect(master.assetData.data.hasBlob).to.equal(true);
+  });
+
+  it("Keeps printed masters from being burned", async () => {
+    await expectError(burn(masterId), "MasterEditionPrinted");
+  });
+});
@@ -0,0 +1,70 @@
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  buy,
+  createAsset,
+  createGroup,
+  expectError,
+  fetchAsset,
+  fetchCpiEvents,
+  fundedKeypair,
+  list,
+  program,
+  setGroupFrozen,
+  transfer,
+} from "./helpers";
+
+describe("group freeze", () => {
+  let group: PublicKey;
+  let owner: Keypair;
+  let assetId: PublicKey;
+
+  before(async () => {
+    group = await createGroup();
+    owner = await fundedKeypair();
+    assetId = await createAsset({ group, recipient: owner.publicKey });
+  });
+
+  it("Lets the group authority freeze the group", async () => {
+    const signature = await setGroupFrozen(group, true);
+
+    const groupData = await program.account.group.fetch(group);
+    expect(groupData.frozen).to.equal(true);
+    const event = (await fetchCpiEvents(signature)).find(
+      ({ name }) => name === "GroupFrozenEvent"
+    );
+    expect(event.data.group.toBase58()).to.equal(group.toBase58());
+    expect(event.data.frozen).to.equal(true);
+  });
+
+  it("Blocks transfers and sales of member assets", async () => {
+    await expectError(
+      transfer(assetId, {
+        authority: owner,
+        recipient: Keypair.generate().publicKey,
+      }),
+      "GroupFrozen"
+    );
+
+    await list(assetId, LAMPORTS_PER_SOL, { authority: owner });
+    const buyer = await fundedKeypair(2 * LAMPORTS_PER_SOL);
+    await expectError(buy(assetId, { buyer }), "GroupFrozen");
+  });
+
+  it("Only lets the group authority toggle the freeze", async () => {
+    const intruder = await fundedKeypair();
+    await expectError(
+      setGroupFrozen(group, false, intruder),
+      "InvalidAuthority"
+    );
+  });
+
+  it("Releases member assets once unfrozen", async () => {
+    await setGroupFrozen(group, false);
+
+    const buyer = await fundedKeypair(2 * LAMPORTS_PER_SOL);
+    await buy(assetId, { buyer });
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(buyer.publicKey.toBase58());
+  });
+});
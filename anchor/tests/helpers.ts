@@ -0,0 +1,1245 @@
+import * as anchor from "@coral-xyz/anchor";
+import { IdlTypes, Program } from "@coral-xyz/anchor";
+import { ZkNft } from "../target/types/zk_nft";
+import {
+  BN254,
+  CompressedProof,
+  LightSystemProgram,
+  Rpc,
+  bn,
+  buildAndSignTx,
+  createRpc,
+  defaultStaticAccountsStruct,
+  defaultTestStateTreeAccounts,
+  deriveAddress,
+  packCompressedAccounts,
+  packNewAddressParams,
+  rpcRequest,
+  sendAndConfirmTx,
+} from "@lightprotocol/stateless.js";
+import {
+  AccountMeta,
+  ComputeBudgetProgram,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import fs from "fs";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import { sha256 } from "@noble/hashes/sha256";
+import bs58 from "bs58";
+
+export type Types = IdlTypes<ZkNft>;
+
+export const keypair = Keypair.fromSecretKey(
+  Uint8Array.from(
+    JSON.parse(fs.readFileSync("target/deploy/authority-keypair.json", "utf-8"))
+  )
+);
+
+export const setComputeUnitIx = ComputeBudgetProgram.setComputeUnitLimit({
+  units: 1_000_000,
+});
+
+export const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+
+export const program = anchor.workspace.ZkNft as Program<ZkNft>;
+
+const connectionArgs: any =
+  provider.connection.rpcEndpoint === "http://localhost:8899"
+    ? [undefined, undefined, undefined]
+    : [
+        "https://zk-testnet.helius.dev:8899", // rpc
+        "https://zk-testnet.helius.dev:8784", // zk compression rpc
+        "https://zk-testnet.helius.dev:3001", // prover
+      ];
+connectionArgs.push({ commitment: "confirmed" });
+
+export const connection: Rpc = createRpc(...connectionArgs);
+
+export const addressTree = defaultTestStateTreeAccounts().addressTree;
+
+export const cpiAuthorityPda = PublicKey.findProgramAddressSync(
+  [Buffer.from("cpi_authority")],
+  program.programId
+)[0];
+
+/** Accounts of every instruction that CPIs into the light system program. */
+export function lightAccounts() {
+  const {
+    accountCompressionAuthority,
+    noopProgram,
+    registeredProgramPda,
+    accountCompressionProgram,
+  } = defaultStaticAccountsStruct();
+
+  return {
+    cpiAuthorityPda,
+    selfProgram: program.programId,
+    lightSystemProgram: LightSystemProgram.programId,
+    accountCompressionAuthority,
+    noopProgram,
+    registeredProgramPda,
+    accountCompressionProgram,
+    systemProgram: SystemProgram.programId,
+  };
+}
+
+/** Mirrors `get_asset_resource_seed`: sha256 of the resource and the asset id. */
+export function resourceSeed(
+  resource: string | Uint8Array,
+  assetId: PublicKey | Uint8Array
+): Uint8Array {
+  return sha256(
+    Buffer.concat([
+      Buffer.from(resource),
+      Buffer.from(assetId instanceof PublicKey ? assetId.toBytes() : assetId),
+    ])
+  );
+}
+
+export async function resourceAddress(
+  resource: string | Uint8Array,
+  assetId: PublicKey | Uint8Array
+): Promise<PublicKey> {
+  return deriveAddress(resourceSeed(resource, assetId), addressTree);
+}
+
+export interface CompressedPda<T = any> {
+  address: PublicKey;
+  hash: BN254;
+  leafIndex: number;
+  tree: PublicKey;
+  lamports: anchor.BN;
+  data: T;
+}
+
+/** Fetches and decodes the compressed account at `address`, if any. */
+export async function fetchCompressed<T = any>(
+  type: string,
+  address: PublicKey
+): Promise<CompressedPda<T> | null> {
+  const unsafeRes = await rpcRequest(
+    connection.compressionApiEndpoint,
+    "getCompressedAccount",
+    {
+      address: address.toBase58(),
+    }
+  );
+  const value = unsafeRes.result.value;
+  if (!value?.data) {
+    return null;
+  }
+
+  return {
+    address,
+    hash: bn(new PublicKey(value.hash).toBytes()),
+    leafIndex: value.leafIndex,
+    tree: new PublicKey(value.tree),
+    lamports: new anchor.BN(value.lamports ?? 0),
+    data: program.coder.types.decode(
+      type,
+      Buffer.from(value.data.data, "base64")
+    ),
+  };
+}
+
+export interface NewAddress {
+  seed: Uint8Array;
+  address: PublicKey;
+}
+
+export async function newAddress(seed: Uint8Array): Promise<NewAddress> {
+  return { seed, address: await deriveAddress(seed, addressTree) };
+}
+
+export interface Prepared {
+  proof: CompressedProof;
+  /** Packed inputs, in the order the accounts were passed. */
+  inputs: Types["PackedInputCompressedPda"][];
+  /** Packed new address params, in the order the addresses were passed. */
+  newAddresses: Types["NewAddressParams"][];
+  remainingAccounts: PublicKey[];
+}
+
+/**
+ * Fetches a validity proof for consuming `inputs` and creating `newAddresses`,
+ * and packs their trees into `remainingAccounts`. The state tree new accounts
+ * are written to is always at index 0.
+ */
+export async function prepare(
+  inputs: CompressedPda[],
+  newAddresses: NewAddress[] = [],
+  remainingAccounts: PublicKey[] = []
+): Promise<Prepared> {
+  const proof = await connection.getValidityProof(
+    inputs.map((input) => input.hash),
+    newAddresses.map(({ address }) => bn(address.toBytes()))
+  );
+
+  const {
+    remainingAccounts: _remainingAccounts,
+    packedInputCompressedAccounts,
+  } = packCompressedAccounts(
+    inputs.map((input, i) => ({
+      address: Array.from(input.address.toBytes()),
+      data: null,
+      owner: program.programId,
+      lamports: input.lamports,
+      hash: input.hash.toArray("be", 32),
+      leafIndex: input.leafIndex,
+      merkleTree: input.tree,
+      nullifierQueue: proof.nullifierQueues[i],
+    })),
+    proof.rootIndices,
+    LightSystemProgram.createNewAddressOutputState(
+      Array.from(PublicKey.default.toBytes()),
+      program.programId
+    ),
+    undefined,
+    remainingAccounts
+  );
+
+  const { newAddressParamsPacked, remainingAccounts: packedRemainingAccounts } =
+    packNewAddressParams(
+      newAddresses.map(({ seed }, i) => ({
+        seed,
+        addressMerkleTreeRootIndex: proof.rootIndices[inputs.length + i],
+        addressMerkleTreePubkey: proof.merkleTrees[inputs.length + i],
+        addressQueuePubkey: proof.nullifierQueues[inputs.length + i],
+      })),
+      _remainingAccounts
+    );
+
+  return {
+    proof: proof.compressedProof,
+    inputs: packedInputCompressedAccounts.map(
+      ({ merkleContext, rootIndex }) => ({
+        merkleContext,
+        rootIndex,
+      })
+    ),
+    newAddresses: newAddressParamsPacked.map((params) => ({
+      addressMerkleTreeAccountIndex: params.addressMerkleTreeAccountIndex,
+      addressQueueAccountIndex: params.addressQueueAccountIndex,
+      addressMerkleTreeRootIndex: params.addressMerkleTreeRootIndex,
+    })),
+    remainingAccounts: packedRemainingAccounts,
+  };
+}
+
+/** Index of `account` in `remainingAccounts`, appending it if missing. */
+export function accountIndex(
+  remainingAccounts: PublicKey[],
+  account: PublicKey
+): number {
+  const index = remainingAccounts.findIndex((key) => key.equals(account));
+  if (index !== -1) {
+    return index;
+  }
+  remainingAccounts.push(account);
+  return remainingAccounts.length - 1;
+}
+
+export function toAccountMetas(
+  remainingAccounts: PublicKey[],
+  extraAccounts: AccountMeta[] = []
+): AccountMeta[] {
+  return [
+    ...remainingAccounts.map((account) => ({
+      pubkey: account,
+      isSigner: false,
+      isWritable: true,
+    })),
+    ...extraAccounts,
+  ];
+}
+
+export interface Asset {
+  assetId: PublicKey;
+  baseData: CompressedPda<Types["BaseData"]>;
+  assetData: CompressedPda<Types["AssetData"]>;
+}
+
+export async function fetchAsset(assetId: PublicKey): Promise<Asset> {
+  const [baseData, assetData] = await Promise.all([
+    fetchCompressed<Types["BaseData"]>("BaseData", assetId),
+    fetchCompressed<Types["AssetData"]>(
+      "AssetData",
+      await resourceAddress("asset_data", assetId)
+    ),
+  ]);
+  return { assetId, baseData, assetData };
+}
+
+export interface PreparedAsset extends Prepared {
+  assetId: number[];
+  baseData: Types["BaseData"];
+  baseDataInput: Types["PackedInputCompressedPda"];
+  assetDataParams: Types["AssetDataParams"] | null;
+  addressTreeIndex: number;
+}
+
+/**
+ * Prepares an instruction consuming the asset's base data, followed by its
+ * asset data unless `withAssetData` is false, followed by `inputs`.
+ */
+export async function prepareAsset(
+  assetId: PublicKey,
+  {
+    withAssetData = true,
+    inputs = [],
+    newAddresses = [],
+    remainingAccounts = [],
+  }: {
+    withAssetData?: boolean;
+    inputs?: CompressedPda[];
+    newAddresses?: NewAddress[];
+    remainingAccounts?: PublicKey[];
+  } = {}
+): Promise<PreparedAsset> {
+  const { baseData, assetData } = await fetchAsset(assetId);
+  const prepared = await prepare(
+    [baseData, ...(withAssetData ? [assetData] : []), ...inputs],
+    newAddresses,
+    remainingAccounts
+  );
+  const addressTreeIndex = accountIndex(
+    prepared.remainingAccounts,
+    addressTree
+  );
+
+  return {
+    ...prepared,
+    assetId: Array.from(assetId.toBytes()),
+    baseData: baseData.data,
+    baseDataInput: prepared.inputs[0],
+    assetDataParams: withAssetData
+      ? {
+          data: assetData.data,
+          input: prepared.inputs[1],
+          addressMerkleTreeAccountIndex: addressTreeIndex,
+        }
+      : null,
+    addressTreeIndex,
+  };
+}
+
+export async function send(
+  ixs: TransactionInstruction[],
+  signers: Keypair[] = [],
+  payer: Keypair = keypair
+): Promise<string> {
+  const blockhash = await connection.getLatestBlockhash();
+  const tx = buildAndSignTx(
+    [setComputeUnitIx, ...ixs],
+    payer,
+    blockhash.blockhash,
+    signers.filter((signer) => !signer.publicKey.equals(payer.publicKey))
+  );
+  return sendAndConfirmTx(connection, tx, {
+    commitment: "confirmed",
+  });
+}
+
+/** Expects `promise` to fail with the program error `code`. */
+export async function expectError(promise: Promise<unknown>, code: string) {
+  let error: any;
+  try {
+    await promise;
+  } catch (e) {
+    error = e;
+  }
+  expect(error, `expected ${code}`).to.exist;
+  const logs: string[] = error.logs ?? error.transactionLogs ?? [];
+  expect([error.message, ...logs].join("\n")).to.include(code);
+}
+
+/** Inner instructions of a confirmed transaction, with their program ids. */
+export async function fetchInnerInstructions(
+  signature: string
+): Promise<{ programId: PublicKey; data: Buffer }[]> {
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const accountKeys = tx.transaction.message.getAccountKeys({
+    accountKeysFromLookups: tx.meta.loadedAddresses,
+  });
+
+  const instructions = [];
+  for (const inner of tx.meta.innerInstructions) {
+    for (const ix of inner.instructions) {
+      instructions.push({
+        programId: accountKeys.get(ix.programIdIndex),
+        data: Buffer.from(bs58.decode(ix.data)),
+      });
+    }
+  }
+  return instructions;
+}
+
+/** Events the program emitted through `emit_cpi!` in a transaction. */
+export async function fetchCpiEvents(signature: string) {
+  const innerInstructions = await fetchInnerInstructions(signature);
+  return innerInstructions
+    .filter(({ programId }) => programId.equals(program.programId))
+    .map(({ data }) =>
+      // skip the event instruction tag
+      program.coder.events.decode(data.subarray(8).toString("base64"))
+    )
+    .filter((event) => event !== null);
+}
+
+export async function fundedKeypair(
+  lamports: number = LAMPORTS_PER_SOL
+): Promise<Keypair> {
+  const funded = Keypair.generate();
+  await send([
+    SystemProgram.transfer({
+      fromPubkey: keypair.publicKey,
+      toPubkey: funded.publicKey,
+      lamports,
+    }),
+  ]);
+  return funded;
+}
+
+export async function now(): Promise<number> {
+  const slot = await connection.getSlot("confirmed");
+  return connection.getBlockTime(slot);
+}
+
+export function sleep(ms: number) {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+/** Waits until the cluster clock reaches `timestamp`. */
+export async function waitUntil(timestamp: number) {
+  while ((await now()) < timestamp) {
+    await sleep(1000);
+  }
+}
+
+export async function createGroup({
+  authority = keypair,
+  maxSize = 0,
+  nonTransferable = false,
+  parent,
+}: {
+  authority?: Keypair;
+  maxSize?: number;
+  nonTransferable?: boolean;
+  parent?: { group: PublicKey; authority: Keypair };
+} = {}): Promise<PublicKey> {
+  const groupKeypair = Keypair.generate();
+
+  const ix = await program.methods
+    .createGroup(new anchor.BN(maxSize), nonTransferable)
+    .accounts({
+      payer: keypair.publicKey,
+      groupAuthority: authority.publicKey,
+      group: groupKeypair.publicKey,
+      parent: parent?.group ?? null,
+      parentAuthority: parent?.authority.publicKey ?? null,
+    })
+    .instruction();
+
+  await send(
+    [ix],
+    [groupKeypair, authority, ...(parent ? [parent.authority] : [])]
+  );
+
+  return groupKeypair.publicKey;
+}
+
+/** A blob pointing at a made up log transaction. */
+export function blobData(data: Partial<Types["Blob"]> = {}): Types["Blob"] {
+  return {
+    contentType: "application/json",
+    contentEncoding: { identity: {} },
+    txSig: Array.from(randomBytes(64)),
+    sharedBlobCreator: null,
+    uploadedBlob: null,
+    hash: null,
+    ...data,
+  };
+}
+
+export interface CreateAssetArgs {
+  recipient?: PublicKey;
+  group?: PublicKey;
+  /** Signs for the group, null to leave the signature out. */
+  groupAuthority?: Keypair | null;
+  authority?: PublicKey | null;
+  blob?: Types["Blob"] | null;
+  sharedBlob?: CompressedPda<Types["SharedBlob"]>;
+  uploadedBlob?: PublicKey;
+  attributes?: Types["Attribute"][] | null;
+  royalties?: Types["Royalties"] | null;
+  nonTransferable?: boolean;
+}
+
+export async function createAssetIx({
+  recipient = keypair.publicKey,
+  group,
+  groupAuthority = keypair,
+  authority = groupAuthority?.publicKey ?? null,
+  blob = null,
+  sharedBlob,
+  uploadedBlob,
+  attributes = null,
+  royalties = null,
+  nonTransferable = false,
+}: CreateAssetArgs = {}) {
+  const baseData = await newAddress(Keypair.generate().publicKey.toBytes());
+  const assetId = baseData.address;
+  const assetData = await newAddress(resourceSeed("asset_data", assetId));
+
+  const prepared = await prepare([], [baseData, assetData]);
+  let remainingAccounts = prepared.remainingAccounts;
+
+  let blobParams: Types["BlobParams"] | null = null;
+  if (blob) {
+    const blobPrepared = await prepare(
+      sharedBlob ? [sharedBlob] : [],
+      [await newAddress(resourceSeed("blob", assetId))],
+      remainingAccounts
+    );
+    remainingAccounts = blobPrepared.remainingAccounts;
+    blobParams = {
+      data: blob,
+      proof: blobPrepared.proof,
+      sharedBlobInput: sharedBlob ? blobPrepared.inputs[0] : null,
+    };
+  }
+
+  let attributesParams: Types["AttributesParams"] | null = null;
+  if (attributes) {
+    const attributesPrepared = await prepare(
+      [],
+      [await newAddress(resourceSeed("attributes", assetId))],
+      remainingAccounts
+    );
+    remainingAccounts = attributesPrepared.remainingAccounts;
+    attributesParams = {
+      data: attributes,
+      proof: attributesPrepared.proof,
+    };
+  }
+
+  const ix = await program.methods
+    .createAsset(
+      prepared.proof,
+      Array.from(baseData.seed),
+      prepared.newAddresses[0],
+      blobParams,
+      attributesParams,
+      royalties,
+      nonTransferable
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      groupAuthority: group ? groupAuthority?.publicKey ?? null : null,
+      authority,
+      recipient,
+      group: group ?? null,
+      uploadedBlob: uploadedBlob ?? null,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(remainingAccounts))
+    .instruction();
+
+  return { ix, assetId };
+}
+
+export async function createAsset(args: CreateAssetArgs = {}) {
+  const { ix, assetId } = await createAssetIx(args);
+  await send(
+    [ix],
+    args.group && args.groupAuthority !== null
+      ? [args.groupAuthority ?? keypair]
+      : []
+  );
+  return assetId;
+}
+
+export interface TransferArgs {
+  authority?: Keypair;
+  recipient: PublicKey;
+  /** Declared sale price in lamports, paying enforced royalties. */
+  salePrice?: number | null;
+  recipientProof?: Types["RecipientProof"] | null;
+  /** Overrides the recipient policy account taken from the group. */
+  recipientPolicy?: PublicKey | null;
+  extraAccounts?: AccountMeta[];
+}
+
+export async function transferIx(
+  assetId: PublicKey,
+  {
+    authority = keypair,
+    recipient,
+    salePrice = null,
+    recipientProof = null,
+    recipientPolicy,
+    extraAccounts = [],
+  }: TransferArgs
+) {
+  const prepared = await prepareAsset(assetId);
+  const group = prepared.assetDataParams.data.group;
+  const groupData = group ? await program.account.group.fetch(group) : null;
+  if (recipientPolicy === undefined) {
+    recipientPolicy = groupData?.recipientPolicy ?? null;
+  }
+
+  return program.methods
+    .transfer(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput,
+      prepared.assetDataParams,
+      salePrice === null ? null : new anchor.BN(salePrice),
+      recipientProof
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: authority.publicKey,
+      recipient,
+      group,
+      recipientPolicy,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(
+      toAccountMetas(prepared.remainingAccounts, extraAccounts)
+    )
+    .instruction();
+}
+
+export async function transfer(assetId: PublicKey, args: TransferArgs) {
+  return send(
+    [await transferIx(assetId, args)],
+    args.authority ? [args.authority] : []
+  );
+}
+
+export async function setGroupFrozen(
+  group: PublicKey,
+  frozen: boolean,
+  groupAuthority: Keypair = keypair
+) {
+  const ix = await program.methods
+    .setGroupFrozen(frozen)
+    .accounts({ groupAuthority: groupAuthority.publicKey, group })
+    .instruction();
+  return send([ix], [groupAuthority]);
+}
+
+export async function setGroupPermanentDelegate(
+  group: PublicKey,
+  permanentDelegate: PublicKey | null,
+  groupAuthority: Keypair = keypair
+) {
+  const ix = await program.methods
+    .setGroupPermanentDelegate(permanentDelegate)
+    .accounts({ groupAuthority: groupAuthority.publicKey, group })
+    .instruction();
+  return send([ix], [groupAuthority]);
+}
+
+export async function list(
+  assetId: PublicKey,
+  price: number,
+  {
+    authority = keypair,
+    paymentMint = null,
+  }: { authority?: Keypair; paymentMint?: PublicKey | null } = {}
+) {
+  const prepared = await prepareAsset(assetId, { withAssetData: false });
+
+  const ix = await program.methods
+    .list(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput,
+      new anchor.BN(price),
+      paymentMint
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: authority.publicKey,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix], [authority]);
+}
+
+export interface TokenPayment {
+  paymentMint: PublicKey;
+  buyerTokenAccount: PublicKey;
+  sellerTokenAccount: PublicKey;
+  tokenProgram: PublicKey;
+}
+
+export interface BuyArgs {
+  buyer: Keypair;
+  recipientProof?: Types["RecipientProof"] | null;
+  payment?: TokenPayment;
+  extraAccounts?: AccountMeta[];
+}
+
+export async function buyIx(
+  assetId: PublicKey,
+  { buyer, recipientProof = null, payment, extraAccounts = [] }: BuyArgs
+) {
+  const prepared = await prepareAsset(assetId);
+  const group = prepared.assetDataParams.data.group;
+  const groupData = group ? await program.account.group.fetch(group) : null;
+
+  return program.methods
+    .buy(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput,
+      prepared.assetDataParams,
+      recipientProof
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      buyer: buyer.publicKey,
+      seller: prepared.baseData.owner,
+      group,
+      recipientPolicy: groupData?.recipientPolicy ?? null,
+      paymentMint: payment?.paymentMint ?? null,
+      buyerTokenAccount: payment?.buyerTokenAccount ?? null,
+      sellerTokenAccount: payment?.sellerTokenAccount ?? null,
+      tokenProgram: payment?.tokenProgram ?? null,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(
+      toAccountMetas(prepared.remainingAccounts, extraAccounts)
+    )
+    .instruction();
+}
+
+export async function buy(assetId: PublicKey, args: BuyArgs) {
+  return send([await buyIx(assetId, args)], [args.buyer]);
+}
+
+/** Writable remaining accounts royalties are paid into. */
+export function creatorAccounts(royalties: Types["Royalties"]): AccountMeta[] {
+  return royalties.creators.map(({ address }) => ({
+    pubkey: address,
+    isSigner: false,
+    isWritable: true,
+  }));
+}
+
+export interface LockArgs {
+  authority?: Keypair;
+  /** Passes the asset data, which the permanent delegate is checked against. */
+  withAssetData?: boolean;
+}
+
+async function lockIx(
+  method: "lock" | "unlock",
+  assetId: PublicKey,
+  { authority = keypair, withAssetData = false }: LockArgs
+) {
+  const prepared = await prepareAsset(assetId, { withAssetData });
+
+  return program.methods[method](
+    prepared.proof,
+    prepared.assetId,
+    prepared.baseData,
+    prepared.baseDataInput,
+    prepared.assetDataParams
+  )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: authority.publicKey,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+}
+
+export async function lock(assetId: PublicKey, args: LockArgs = {}) {
+  const ix = await lockIx("lock", assetId, args);
+  return send([ix], [args.authority ?? keypair]);
+}
+
+export async function unlock(assetId: PublicKey, args: LockArgs = {}) {
+  const ix = await lockIx("unlock", assetId, args);
+  return send([ix], [args.authority ?? keypair]);
+}
+
+export interface BurnArgs {
+  authority?: Keypair;
+  /** Overrides the group account taken from the asset data. */
+  group?: PublicKey | null;
+  /** Leaves the blob out of the burn even when the asset has one. */
+  withBlob?: boolean;
+  /** Leaves the attributes out of the burn even when the asset has them. */
+  withAttributes?: boolean;
+}
+
+export async function burnIx(
+  assetId: PublicKey,
+  { authority = keypair, group, withBlob, withAttributes }: BurnArgs = {}
+) {
+  // prints refer to their master's blob and attributes
+  const { assetData } = await fetchAsset(assetId);
+  if (group === undefined) {
+    group = assetData.data.group;
+  }
+  const isPrint = !!assetData.data.edition?.print;
+  if (withBlob === undefined) {
+    withBlob = assetData.data.hasBlob && !isPrint;
+  }
+  if (withAttributes === undefined) {
+    withAttributes = assetData.data.hasAttributes && !isPrint;
+  }
+
+  const blob = withBlob
+    ? await fetchCompressed<Types["Blob"]>(
+        "Blob",
+        await resourceAddress("blob", assetId)
+      )
+    : null;
+  const attributes = withAttributes
+    ? await fetchCompressed<Types["Attributes"]>(
+        "Attributes",
+        await resourceAddress("attributes", assetId)
+      )
+    : null;
+  const prepared = await prepareAsset(assetId, {
+    inputs: [blob, attributes].filter((input) => input !== null),
+  });
+  let next = 2;
+
+  return program.methods
+    .burn(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput,
+      prepared.assetDataParams,
+      blob
+        ? {
+            data: blob.data,
+            input: prepared.inputs[next++],
+            addressMerkleTreeAccountIndex: prepared.addressTreeIndex,
+          }
+        : null,
+      attributes
+        ? {
+            data: attributes.data,
+            input: prepared.inputs[next++],
+            addressMerkleTreeAccountIndex: prepared.addressTreeIndex,
+          }
+        : null
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: authority.publicKey,
+      group,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+}
+
+export async function burn(assetId: PublicKey, args: BurnArgs = {}) {
+  return send([await burnIx(assetId, args)], [args.authority ?? keypair]);
+}
+
+export function mintConfigParams(
+  params: Partial<Types["MintConfigParams"]> = {}
+): Types["MintConfigParams"] {
+  return {
+    treasury: keypair.publicKey,
+    price: new anchor.BN(0),
+    paymentMint: null,
+    startTime: null,
+    endTime: null,
+    walletLimit: null,
+    maxSupply: null,
+    phases: [],
+    reveal: null,
+    ...params,
+  };
+}
+
+export async function createMintConfig(
+  group: PublicKey,
+  params: Types["MintConfigParams"],
+  {
+    groupAuthority = keypair,
+    addressMerkleTree = addressTree,
+  }: { groupAuthority?: Keypair; addressMerkleTree?: PublicKey } = {}
+) {
+  const { mintConfigs } = await program.account.group.fetch(group);
+  const mintConfig = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("mint_config"),
+      group.toBuffer(),
+      mintConfigs.toArrayLike(Buffer, "le", 8),
+    ],
+    program.programId
+  )[0];
+
+  const ix = await program.methods
+    .createMintConfig(params)
+    .accounts({
+      payer: keypair.publicKey,
+      groupAuthority: groupAuthority.publicKey,
+      group,
+      mintConfig,
+      addressMerkleTree,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  await send([ix], [groupAuthority]);
+  return mintConfig;
+}
+
+/** Mirrors `MintRecord::seed`. */
+export function mintRecordSeed(
+  mintConfig: PublicKey,
+  wallet: PublicKey,
+  phase: number | null
+) {
+  return resourceSeed(
+    Buffer.concat([
+      Buffer.from("mint_record"),
+      wallet.toBuffer(),
+      Buffer.from(phase === null ? [0] : [1, phase]),
+    ]),
+    mintConfig
+  );
+}
+
+export interface MintPayment {
+  paymentMint: PublicKey;
+  buyerTokenAccount: PublicKey;
+  treasuryTokenAccount: PublicKey;
+  tokenProgram: PublicKey;
+}
+
+export interface MintArgs {
+  buyer: Keypair;
+  phase?: number | null;
+  allowlistProof?: Types["AllowlistProof"] | null;
+  payment?: MintPayment;
+  /** Leaves the placeholder proof out of reveal configs. */
+  withPlaceholder?: boolean;
+}
+
+/**
+ * Prepares a mint of the config's next asset, with the buyer's mint record
+ * when a wallet limit applies and the placeholder when the config reveals.
+ */
+export async function mintFromConfigIx(
+  mintConfig: PublicKey,
+  {
+    buyer,
+    phase = null,
+    allowlistProof = null,
+    payment,
+    withPlaceholder = true,
+  }: MintArgs
+) {
+  const config = await program.account.mintConfig.fetch(mintConfig);
+  const assetSeed = resourceSeed(
+    Buffer.concat([
+      Buffer.from("mint"),
+      config.minted.toArrayLike(Buffer, "le", 8),
+    ]),
+    mintConfig
+  );
+  const baseData = await newAddress(assetSeed);
+  const assetId = baseData.address;
+  const newAddresses = [
+    baseData,
+    await newAddress(resourceSeed("asset_data", assetId)),
+  ];
+
+  // allowlisted phases limit mints to the wallet's allocation
+  const walletLimit =
+    phase !== null && config.phases[phase]?.allowlistRoot
+      ? allowlistProof?.allocation ?? null
+      : config.walletLimit;
+  const inputs: CompressedPda[] = [];
+  let mintRecord: CompressedPda<Types["MintRecord"]> | null = null;
+  if (walletLimit !== null) {
+    const seed = mintRecordSeed(mintConfig, buyer.publicKey, phase);
+    const address = await newAddress(seed);
+    mintRecord = await fetchCompressed("MintRecord", address.address);
+    if (mintRecord) {
+      inputs.push(mintRecord);
+    } else {
+      newAddresses.push(address);
+    }
+  }
+  const prepared = await prepare(inputs, newAddresses);
+
+  let placeholderProof: CompressedProof | null = null;
+  let remainingAccounts = prepared.remainingAccounts;
+  if (config.reveal && withPlaceholder) {
+    const placeholderPrepared = await prepare(
+      [],
+      [
+        await newAddress(resourceSeed("blob", assetId)),
+        await newAddress(resourceSeed("attributes", assetId)),
+      ],
+      remainingAccounts
+    );
+    placeholderProof = placeholderPrepared.proof;
+    remainingAccounts = placeholderPrepared.remainingAccounts;
+  }
+
+  const ix = await program.methods
+    .mintFromConfig(
+      prepared.proof,
+      prepared.newAddresses[0],
+      phase,
+      allowlistProof,
+      mintRecord
+        ? {
+            data: mintRecord.data,
+            input: prepared.inputs[0],
+            addressMerkleTreeAccountIndex:
+              prepared.newAddresses[0].addressMerkleTreeAccountIndex,
+          }
+        : null,
+      placeholderProof
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      buyer: buyer.publicKey,
+      mintConfig,
+      group: config.group,
+      treasury: config.treasury,
+      paymentMint: payment?.paymentMint ?? null,
+      buyerTokenAccount: payment?.buyerTokenAccount ?? null,
+      treasuryTokenAccount: payment?.treasuryTokenAccount ?? null,
+      tokenProgram: payment?.tokenProgram ?? null,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(remainingAccounts))
+    .instruction();
+
+  return { ix, assetId };
+}
+
+export async function mintFromConfig(mintConfig: PublicKey, args: MintArgs) {
+  const { ix, assetId } = await mintFromConfigIx(mintConfig, args);
+  await send([ix], [args.buyer]);
+  return assetId;
+}
+
+const MAX_BLOB_UPLOADERS = 4;
+const UPLOADED_BLOB_BUFFER_START =
+  8 + // discriminator
+  32 + // authority
+  1 + // content encoding
+  32 * MAX_BLOB_UPLOADERS + // uploaders
+  1 + // finalized
+  32; // hash
+
+/**
+ * Creates an uploaded blob buffer holding `bytes`, and logs it unless
+ * `finalize` is false.
+ */
+export async function uploadBlob(
+  bytes: Buffer,
+  {
+    authority = keypair,
+    contentEncoding = { identity: {} },
+    finalize = true,
+  }: {
+    authority?: Keypair;
+    contentEncoding?: Types["ContentEncoding"];
+    finalize?: boolean;
+  } = {}
+): Promise<PublicKey> {
+  const uploadedBlob = Keypair.generate();
+  const space = UPLOADED_BLOB_BUFFER_START + bytes.length;
+
+  await send(
+    [
+      SystemProgram.createAccount({
+        fromPubkey: keypair.publicKey,
+        newAccountPubkey: uploadedBlob.publicKey,
+        space,
+        lamports: await connection.getMinimumBalanceForRentExemption(space),
+        programId: program.programId,
+      }),
+      await program.methods
+        .initBlobUpload(bytes.length, contentEncoding)
+        .accounts({
+          uploadedBlob: uploadedBlob.publicKey,
+          authority: authority.publicKey,
+        })
+        .instruction(),
+      await program.methods
+        .uploadBlob(0, bytes)
+        .accounts({
+          uploadedBlob: uploadedBlob.publicKey,
+          authority: authority.publicKey,
+        })
+        .instruction(),
+    ],
+    [uploadedBlob, authority]
+  );
+
+  if (finalize) {
+    await logBlob(uploadedBlob.publicKey, authority);
+  }
+
+  return uploadedBlob.publicKey;
+}
+
+export async function logBlob(
+  uploadedBlob: PublicKey,
+  authority: Keypair = keypair
+): Promise<string> {
+  const ix = await program.methods
+    .logBlob()
+    .accounts({
+      uploadedBlob,
+      authority: authority.publicKey,
+      noopProgram: defaultStaticAccountsStruct().noopProgram,
+    })
+    .instruction();
+  return send([ix], [authority]);
+}
+
+/**
+ * Binary Merkle tree matching `utils::merkle`: leaves and nodes are domain
+ * separated, and an odd node is paired with itself.
+ */
+export class MerkleTree {
+  readonly layers: Uint8Array[][];
+
+  constructor(leaves: Uint8Array[]) {
+    let layer = leaves.map((leaf) =>
+      sha256(Buffer.concat([Buffer.from([0]), leaf]))
+    );
+    this.layers = [layer];
+    while (layer.length > 1) {
+      const next: Uint8Array[] = [];
+      for (let i = 0; i < layer.length; i += 2) {
+        const right = layer[i + 1] ?? layer[i];
+        next.push(sha256(Buffer.concat([Buffer.from([1]), layer[i], right])));
+      }
+      this.layers.push(next);
+      layer = next;
+    }
+  }
+
+  get root(): number[] {
+    return Array.from(this.layers[this.layers.length - 1][0]);
+  }
+
+  proof(index: number): Types["MerkleProof"] {
+    const proof: number[][] = [];
+    let position = index;
+    for (const layer of this.layers.slice(0, -1)) {
+      const sibling = layer[position ^ 1] ?? layer[position];
+      proof.push(Array.from(sibling));
+      position >>= 1;
+    }
+    return { index, proof };
+  }
+}
+
+export async function createMasterEdition(
+  assetId: PublicKey,
+  maxSupply: number | null,
+  authority: Keypair = keypair
+) {
+  const prepared = await prepareAsset(assetId);
+  const ix = await program.methods
+    .createMasterEdition(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput,
+      prepared.assetDataParams,
+      maxSupply === null ? null : new anchor.BN(maxSupply)
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: authority.publicKey,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix], [authority]);
+}
+
+/** Mirrors the print's base data seed, numbered from 1. */
+function printSeed(masterId: PublicKey, number: number) {
+  return resourceSeed(
+    Buffer.concat([
+      Buffer.from("edition"),
+      new anchor.BN(number).toArrayLike(Buffer, "le", 8),
+    ]),
+    masterId
+  );
+}
+
+export interface PrintEditionArgs {
+  owner?: Keypair;
+  /** Signs for the master's group, null to leave the signature out. */
+  groupAuthority?: Keypair | null;
+}
+
+export async function printEdition(
+  masterId: PublicKey,
+  recipient: PublicKey,
+  { owner = keypair, groupAuthority = keypair }: PrintEditionArgs = {}
+) {
+  const { assetData } = await fetchAsset(masterId);
+  const number = (assetData.data.edition?.master?.supply.toNumber() ?? 0) + 1;
+  const baseData = await newAddress(printSeed(masterId, number));
+  const prepared = await prepareAsset(masterId, {
+    newAddresses: [
+      baseData,
+      await newAddress(resourceSeed("asset_data", baseData.address)),
+    ],
+  });
+  const group = assetData.data.group;
+
+  const ix = await program.methods
+    .printEdition(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput,
+      prepared.assetDataParams,
+      prepared.newAddresses[0]
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: owner.publicKey,
+      recipient,
+      group,
+      groupAuthority: group ? groupAuthority?.publicKey ?? null : null,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  await send([ix], group && groupAuthority ? [owner, groupAuthority] : [owner]);
+  return baseData.address;
+}
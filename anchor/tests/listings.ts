@@ -0,0 +1,126 @@
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  buy,
+  connection,
+  cpiAuthorityPda,
+  createAsset,
+  expectError,
+  fetchAsset,
+  fundedKeypair,
+  keypair,
+  lightAccounts,
+  list,
+  lock,
+  prepareAsset,
+  program,
+  send,
+  toAccountMetas,
+  transfer,
+} from "./helpers";
+
+async function delist(assetId: PublicKey, authority: Keypair) {
+  const prepared = await prepareAsset(assetId, { withAssetData: false });
+  const ix = await program.methods
+    .delist(
+      prepared.proof,
+      prepared.assetId,
+      prepared.baseData,
+      prepared.baseDataInput
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      authority: authority.publicKey,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix], [authority]);
+}
+
+describe("listings", () => {
+  const price = LAMPORTS_PER_SOL / 2;
+  let seller: Keypair;
+  let buyer: Keypair;
+
+  before(async () => {
+    seller = await fundedKeypair();
+    buyer = await fundedKeypair(2 * LAMPORTS_PER_SOL);
+  });
+
+  it("Locks listed assets under the program's delegation", async () => {
+    const assetId = await createAsset({ recipient: seller.publicKey });
+    await list(assetId, price, { authority: seller });
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.price.toNumber()).to.equal(price);
+    expect(baseData.data.paymentMint).to.equal(null);
+    expect(baseData.data.state).to.deep.equal({ lockedByDelegate: {} });
+    expect(baseData.data.delegate.toBase58()).to.equal(
+      cpiAuthorityPda.toBase58()
+    );
+    await expectError(
+      transfer(assetId, {
+        authority: seller,
+        recipient: Keypair.generate().publicKey,
+      }),
+      "AssetIsLocked"
+    );
+  });
+
+  it("Sells a listed asset to the buyer", async () => {
+    const assetId = await createAsset({ recipient: seller.publicKey });
+    await list(assetId, price, { authority: seller });
+    const balance = await connection.getBalance(seller.publicKey);
+
+    await buy(assetId, { buyer });
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(buyer.publicKey.toBase58());
+    expect(baseData.data.price).to.equal(null);
+    expect(baseData.data.state).to.deep.equal({ unlocked: {} });
+    expect(baseData.data.delegate).to.equal(null);
+    expect(await connection.getBalance(seller.publicKey)).to.equal(
+      balance + price
+    );
+  });
+
+  it("Unlocks the asset when delisted", async () => {
+    const assetId = await createAsset({ recipient: seller.publicKey });
+    await list(assetId, price, { authority: seller });
+    await delist(assetId, seller);
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.price).to.equal(null);
+    expect(baseData.data.state).to.deep.equal({ unlocked: {} });
+    await expectError(buy(assetId, { buyer }), "AssetNotListed");
+    await expectError(delist(assetId, seller), "AssetNotListed");
+  });
+
+  it("Rejects buying an asset that was never listed", async () => {
+    const assetId = await createAsset({ recipient: seller.publicKey });
+    await expectError(buy(assetId, { buyer }), "AssetNotListed");
+  });
+
+  it("Only lets the owner list and delist", async () => {
+    const assetId = await createAsset({ recipient: seller.publicKey });
+    await expectError(
+      list(assetId, price, { authority: buyer }),
+      "InvalidAuthority"
+    );
+
+    await list(assetId, price, { authority: seller });
+    await expectError(delist(assetId, buyer), "InvalidAuthority");
+  });
+
+  it("Rejects free and locked listings", async () => {
+    const assetId = await createAsset({ recipient: seller.publicKey });
+    await expectError(list(assetId, 0, { authority: seller }), "InvalidPrice");
+
+    await lock(assetId, { authority: seller });
+    await expectError(
+      list(assetId, price, { authority: seller }),
+      "AssetIsLocked"
+    );
+  });
+});
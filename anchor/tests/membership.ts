@@ -0,0 +1,143 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  accountIndex,
+  addressTree,
+  createAsset,
+  createAssetIx,
+  createGroup,
+  expectError,
+  fetchAsset,
+  fetchCpiEvents,
+  fundedKeypair,
+  keypair,
+  lightAccounts,
+  prepare,
+  program,
+  send,
+  toAccountMetas,
+} from "./helpers";
+
+async function setMembershipVerified(
+  assetId: PublicKey,
+  group: PublicKey,
+  verified: boolean,
+  groupAuthority: Keypair = keypair
+) {
+  const { assetData } = await fetchAsset(assetId);
+  const prepared = await prepare([assetData]);
+
+  const ix = await program.methods
+    .setMembershipVerified(
+      prepared.proof,
+      Array.from(assetId.toBytes()),
+      {
+        data: assetData.data,
+        input: prepared.inputs[0],
+        addressMerkleTreeAccountIndex: accountIndex(
+          prepared.remainingAccounts,
+          addressTree
+        ),
+      },
+      verified
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      groupAuthority: groupAuthority.publicKey,
+      group,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix], [groupAuthority]);
+}
+
+describe("group membership", () => {
+  let group: PublicKey;
+  let assetId: PublicKey;
+
+  before(async () => {
+    group = await createGroup({ maxSize: 2 });
+  });
+
+  it("Verifies assets minted by the group authority", async () => {
+    assetId = await createAsset({ group });
+
+    const { assetData } = await fetchAsset(assetId);
+    expect(assetData.data.group.toBase58()).to.equal(group.toBase58());
+    expect(assetData.data.verified).to.equal(true);
+    expect(assetData.data.authority.toBase58()).to.equal(
+      keypair.publicKey.toBase58()
+    );
+
+    const groupData = await program.account.group.fetch(group);
+    expect(groupData.size.toNumber()).to.equal(1);
+  });
+
+  it("Requires the group authority to sign for the group", async () => {
+    const { ix: unsigned } = await createAssetIx({
+      group,
+      groupAuthority: null,
+    });
+    await expectError(send([unsigned]), "GroupAuthorityMissing");
+
+    const intruder = await fundedKeypair();
+    const { ix: wrongAuthority } = await createAssetIx({
+      group,
+      groupAuthority: intruder,
+    });
+    await expectError(send([wrongAuthority], [intruder]), "InvalidAuthority");
+  });
+
+  it("Stops minting once the group is full", async () => {
+    await createAsset({ group });
+
+    const { ix } = await createAssetIx({ group });
+    await expectError(send([ix]), "GroupMaxSizeExceeded");
+  });
+
+  it("Lets the group authority unverify and reverify an asset", async () => {
+    const signature = await setMembershipVerified(assetId, group, false);
+
+    let { assetData } = await fetchAsset(assetId);
+    expect(assetData.data.verified).to.equal(false);
+    const event = (await fetchCpiEvents(signature)).find(
+      ({ name }) => name === "MembershipUpdatedEvent"
+    );
+    expect(event.data.group.toBase58()).to.equal(group.toBase58());
+    expect(event.data.verified).to.equal(false);
+
+    await setMembershipVerified(assetId, group, true);
+    ({ assetData } = await fetchAsset(assetId));
+    expect(assetData.data.verified).to.equal(true);
+  });
+
+  it("Rejects membership updates from other groups", async () => {
+    const otherGroup = await createGroup();
+    await expectError(
+      setMembershipVerified(assetId, otherGroup, false),
+      "GroupMismatch"
+    );
+
+    const intruder = await fundedKeypair();
+    await expectError(
+      setMembershipVerified(assetId, group, false, intruder),
+      "InvalidAuthority"
+    );
+  });
+
+  it("Includes the group path in membership events", async () => {
+    const collection = await createGroup({
+      parent: { group, authority: keypair },
+    });
+    const memberId = await createAsset({ group: collection });
+
+    const signature = await setMembershipVerified(memberId, collection, false);
+    const event = (await fetchCpiEvents(signature)).find(
+      ({ name }) => name === "MembershipUpdatedEvent"
+    );
+    expect(
+      event.data.groupPath.map((parent: PublicKey) => parent.toBase58())
+    ).to.deep.equal([group.toBase58()]);
+  });
+});
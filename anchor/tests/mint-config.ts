@@ -0,0 +1,162 @@
+import * as anchor from "@coral-xyz/anchor";
+import { defaultTestStateTreeAccounts } from "@lightprotocol/stateless.js";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  Types,
+  connection,
+  createGroup,
+  createMintConfig,
+  expectError,
+  fetchAsset,
+  fundedKeypair,
+  keypair,
+  mintConfigParams,
+  mintFromConfig,
+  now,
+  program,
+  send,
+} from "./helpers";
+
+async function updateMintConfig(
+  mintConfig: PublicKey,
+  params: Types["MintConfigParams"],
+  groupAuthority: Keypair = keypair
+) {
+  const { group } = await program.account.mintConfig.fetch(mintConfig);
+  const ix = await program.methods
+    .updateMintConfig(params)
+    .accounts({ groupAuthority: groupAuthority.publicKey, group, mintConfig })
+    .instruction();
+  return send([ix], [groupAuthority]);
+}
+
+async function closeMintConfig(mintConfig: PublicKey) {
+  const { group } = await program.account.mintConfig.fetch(mintConfig);
+  const ix = await program.methods
+    .closeMintConfig()
+    .accounts({
+      receiver: keypair.publicKey,
+      groupAuthority: keypair.publicKey,
+      group,
+      mintConfig,
+    })
+    .instruction();
+  return send([ix]);
+}
+
+describe("mint configs", () => {
+  const price = new anchor.BN(LAMPORTS_PER_SOL / 10);
+  let group: PublicKey;
+  let buyer: Keypair;
+
+  before(async () => {
+    group = await createGroup();
+    buyer = await fundedKeypair(2 * LAMPORTS_PER_SOL);
+  });
+
+  it("Numbers the configs of a group", async () => {
+    const first = await createMintConfig(group, mintConfigParams());
+    const second = await createMintConfig(group, mintConfigParams());
+
+    expect(first.toBase58()).to.not.equal(second.toBase58());
+    const configs = await Promise.all(
+      [first, second].map((config) => program.account.mintConfig.fetch(config))
+    );
+    expect(configs.map(({ id }) => id.toNumber())).to.deep.equal([0, 1]);
+    const groupData = await program.account.group.fetch(group);
+    expect(groupData.mintConfigs.toNumber()).to.equal(2);
+  });
+
+  it("Mints verified assets and pays the treasury", async () => {
+    const treasury = Keypair.generate().publicKey;
+    const mintConfig = await createMintConfig(
+      group,
+      mintConfigParams({ treasury, price })
+    );
+    const assetId = await mintFromConfig(mintConfig, { buyer });
+
+    const { baseData, assetData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(buyer.publicKey.toBase58());
+    expect(assetData.data.group.toBase58()).to.equal(group.toBase58());
+    expect(assetData.data.verified).to.equal(true);
+    expect(assetData.data.authority.toBase58()).to.equal(mintConfig.toBase58());
+    expect(await connection.getBalance(treasury)).to.equal(price.toNumber());
+    const config = await program.account.mintConfig.fetch(mintConfig);
+    expect(config.minted.toNumber()).to.equal(1);
+  });
+
+  it("Stops minting at the max supply", async () => {
+    const mintConfig = await createMintConfig(
+      group,
+      mintConfigParams({ maxSupply: new anchor.BN(1) })
+    );
+    await mintFromConfig(mintConfig, { buyer });
+    await expectError(mintFromConfig(mintConfig, { buyer }), "MintSoldOut");
+  });
+
+  it("Only mints while the sale is open", async () => {
+    const timestamp = await now();
+    for (const window of [
+      { startTime: new anchor.BN(timestamp + 3600) },
+      { endTime: new anchor.BN(timestamp - 60) },
+    ]) {
+      const mintConfig = await createMintConfig(
+        group,
+        mintConfigParams(window)
+      );
+      await expectError(mintFromConfig(mintConfig, { buyer }), "MintNotOpen");
+    }
+  });
+
+  it("Mints into the config's address tree only", async () => {
+    const mintConfig = await createMintConfig(group, mintConfigParams(), {
+      addressMerkleTree: defaultTestStateTreeAccounts().merkleTree,
+    });
+    await expectError(
+      mintFromConfig(mintConfig, { buyer }),
+      "InvalidAddressMerkleTree"
+    );
+  });
+
+  it("Lets the group authority update the config", async () => {
+    const mintConfig = await createMintConfig(group, mintConfigParams());
+    await updateMintConfig(mintConfig, mintConfigParams({ price }));
+
+    const config = await program.account.mintConfig.fetch(mintConfig);
+    expect(config.price.toNumber()).to.equal(price.toNumber());
+
+    const intruder = await fundedKeypair();
+    await expectError(
+      updateMintConfig(mintConfig, mintConfigParams(), intruder),
+      "InvalidAuthority"
+    );
+  });
+
+  it("Only closes configs once minting is over", async () => {
+    const mintConfig = await createMintConfig(group, mintConfigParams());
+    await expectError(closeMintConfig(mintConfig), "MintNotClosed");
+
+    await updateMintConfig(
+      mintConfig,
+      mintConfigParams({ endTime: new anchor.BN(await now()) })
+    );
+    await closeMintConfig(mintConfig);
+    expect(await connection.getAccountInfo(mintConfig)).to.equal(null);
+  });
+
+  it("Never reuses a closed config's address", async () => {
+    const mintConfig = await createMintConfig(
+      group,
+      mintConfigParams({ maxSupply: new anchor.BN(0) })
+    );
+    const { id } = await program.account.mintConfig.fetch(mintConfig);
+    await closeMintConfig(mintConfig);
+
+    expect(await connection.getAccountInfo(mintConfig)).to.equal(null);
+    const next = await createMintConfig(group, mintConfigParams());
+    expect(next.toBase58()).to.not.equal(mintConfig.toBase58());
+    const config = await program.account.mintConfig.fetch(next);
+    expect(config.id.toNumber()).to.equal(id.toNumber() + 1);
+  });
+});
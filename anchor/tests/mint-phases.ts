@@ -0,0 +1,196 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  MerkleTree,
+  Types,
+  createGroup,
+  createMintConfig,
+  expectError,
+  fetchCompressed,
+  fundedKeypair,
+  mintConfigParams,
+  mintFromConfig,
+  mintRecordSeed,
+  newAddress,
+  now,
+} from "./helpers";
+
+interface Allocation {
+  wallet: PublicKey;
+  allocation: number;
+}
+
+/** Allowlist leaf: the wallet followed by its little-endian allocation. */
+function allowlistLeaf({ wallet, allocation }: Allocation) {
+  const allocationBytes = Buffer.alloc(4);
+  allocationBytes.writeUInt32LE(allocation);
+  return Buffer.concat([wallet.toBuffer(), allocationBytes]);
+}
+
+function allowlistProof(
+  allowlist: Allocation[],
+  index: number,
+  allocation = allowlist[index].allocation
+): Types["AllowlistProof"] {
+  const tree = new MerkleTree(allowlist.map(allowlistLeaf));
+  return { allocation, proof: tree.proof(index) };
+}
+
+async function mintCount(
+  mintConfig: PublicKey,
+  wallet: PublicKey,
+  phase: number | null
+) {
+  const { address } = await newAddress(
+    mintRecordSeed(mintConfig, wallet, phase)
+  );
+  const mintRecord = await fetchCompressed<Types["MintRecord"]>(
+    "MintRecord",
+    address
+  );
+  return mintRecord?.data.count ?? 0;
+}
+
+describe("mint phases", () => {
+  let allowed: Keypair;
+  let outsider: Keypair;
+  let allowlist: Allocation[];
+  let mintConfig: PublicKey;
+
+  before(async () => {
+    allowed = await fundedKeypair(LAMPORTS_PER_SOL);
+    outsider = await fundedKeypair(LAMPORTS_PER_SOL);
+    allowlist = [
+      { wallet: Keypair.generate().publicKey, allocation: 1 },
+      { wallet: allowed.publicKey, allocation: 2 },
+      { wallet: Keypair.generate().publicKey, allocation: 3 },
+    ];
+
+    const timestamp = await now();
+    const group = await createGroup();
+    mintConfig = await createMintConfig(
+      group,
+      mintConfigParams({
+        walletLimit: 1,
+        phases: [
+          {
+            price: new anchor.BN(0),
+            startTime: null,
+            endTime: null,
+            allowlistRoot: new MerkleTree(allowlist.map(allowlistLeaf)).root,
+          },
+          {
+            price: new anchor.BN(0),
+            startTime: new anchor.BN(timestamp + 3600),
+            endTime: null,
+            allowlistRoot: null,
+          },
+        ],
+      })
+    );
+  });
+
+  it("Mints an allowlisted wallet's allocation", async () => {
+    const args = {
+      buyer: allowed,
+      phase: 0,
+      allowlistProof: allowlistProof(allowlist, 1),
+    };
+    await mintFromConfig(mintConfig, args);
+    await mintFromConfig(mintConfig, args);
+    expect(await mintCount(mintConfig, allowed.publicKey, 0)).to.equal(2);
+
+    await expectError(mintFromConfig(mintConfig, args), "WalletLimitReached");
+  });
+
+  it("Rejects wallets outside the allowlist", async () => {
+    await expectError(
+      mintFromConfig(mintConfig, { buyer: outsider, phase: 0 }),
+      "NotAllowlisted"
+    );
+    await expectError(
+      mintFromConfig(mintConfig, {
+        buyer: outsider,
+        phase: 0,
+        allowlistProof: allowlistProof(allowlist, 1),
+      }),
+      "NotAllowlisted"
+    );
+  });
+
+  it("Rejects allocations other than the allowlisted one", async () => {
+    const buyer = await fundedKeypair();
+    const inflated = [...allowlist, { wallet: buyer.publicKey, allocation: 1 }];
+    await expectError(
+      mintFromConfig(mintConfig, {
+        buyer,
+        phase: 0,
+        allowlistProof: allowlistProof(inflated, 3, 5),
+      }),
+      "NotAllowlisted"
+    );
+  });
+
+  it("Counts the public sale separately from the phases", async () => {
+    await mintFromConfig(mintConfig, { buyer: allowed });
+    expect(await mintCount(mintConfig, allowed.publicKey, null)).to.equal(1);
+    expect(await mintCount(mintConfig, allowed.publicKey, 0)).to.equal(2);
+
+    await expectError(
+      mintFromConfig(mintConfig, { buyer: allowed }),
+      "WalletLimitReached"
+    );
+  });
+
+  it("Applies the wallet limit to each open phase", async () => {
+    const open = {
+      price: new anchor.BN(0),
+      startTime: null,
+      endTime: null,
+      allowlistRoot: null,
+    };
+    const openMintConfig = await createMintConfig(
+      await createGroup(),
+      mintConfigParams({ walletLimit: 1, phases: [open, open] })
+    );
+
+    for (const phase of [0, 1]) {
+      await mintFromConfig(openMintConfig, { buyer: outsider, phase });
+      expect(
+        await mintCount(openMintConfig, outsider.publicKey, phase)
+      ).to.equal(1);
+    }
+    await expectError(
+      mintFromConfig(openMintConfig, { buyer: outsider, phase: 0 }),
+      "WalletLimitReached"
+    );
+  });
+
+  it("Only mints in open, existing phases", async () => {
+    await expectError(
+      mintFromConfig(mintConfig, { buyer: outsider, phase: 1 }),
+      "MintNotOpen"
+    );
+    await expectError(
+      mintFromConfig(mintConfig, { buyer: outsider, phase: 2 }),
+      "InvalidMintPhase"
+    );
+  });
+
+  it("Rejects configs with too many phases", async () => {
+    const phase = {
+      price: new anchor.BN(0),
+      startTime: null,
+      endTime: null,
+      allowlistRoot: null,
+    };
+    await expectError(
+      createMintConfig(
+        await createGroup(),
+        mintConfigParams({ phases: Array.from({ length: 5 }, () => phase) })
+      ),
+      "TooManyMintPhases"
+    );
+  });
+});
@@ -0,0 +1,117 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  createAssetIx,
+  createGroup,
+  expectError,
+  fetchCpiEvents,
+  fundedKeypair,
+  keypair,
+  program,
+  send,
+  setGroupFrozen,
+} from "./helpers";
+
+const MAX_GROUP_DEPTH = 4;
+
+describe("nested groups", () => {
+  let franchise: Keypair;
+  let root: PublicKey;
+
+  before(async () => {
+    franchise = await fundedKeypair();
+    root = await createGroup({ authority: franchise });
+  });
+
+  it("Records the parent and the path of nested groups", async () => {
+    const collection = await createGroup({
+      parent: { group: root, authority: franchise },
+    });
+    const subCollection = await createGroup({
+      parent: { group: collection, authority: keypair },
+    });
+
+    const rootData = await program.account.group.fetch(root);
+    expect(rootData.parent).to.be.null;
+    expect(rootData.path).to.be.empty;
+    const collectionData = await program.account.group.fetch(collection);
+    expect(collectionData.parent.equals(root)).to.be.true;
+    expect(collectionData.authority.equals(keypair.publicKey)).to.be.true;
+    const { parent, path } = await program.account.group.fetch(subCollection);
+    expect(parent.equals(collection)).to.be.true;
+    expect(path.map((group) => group.toBase58())).to.deep.equal([
+      root.toBase58(),
+      collection.toBase58(),
+    ]);
+  });
+
+  it("Requires the parent authority to sign", async () => {
+    const group = Keypair.generate();
+    const ix = await program.methods
+      .createGroup(new anchor.BN(0), false)
+      .accounts({
+        payer: keypair.publicKey,
+        groupAuthority: keypair.publicKey,
+        group: group.publicKey,
+        parent: root,
+        parentAuthority: null,
+      })
+      .instruction();
+    await expectError(send([ix], [group]), "ParentAuthorityMissing");
+
+    await expectError(
+      createGroup({ parent: { group: root, authority: keypair } }),
+      "InvalidAuthority"
+    );
+  });
+
+  it("Limits the nesting depth", async () => {
+    let group = root;
+    for (let depth = 0; depth < MAX_GROUP_DEPTH; depth++) {
+      group = await createGroup({
+        authority: franchise,
+        parent: { group, authority: franchise },
+      });
+    }
+    const { path } = await program.account.group.fetch(group);
+    expect(path).to.have.length(MAX_GROUP_DEPTH);
+
+    await expectError(
+      createGroup({ parent: { group, authority: franchise } }),
+      "GroupTooDeep"
+    );
+  });
+
+  it("Includes the group path in group events", async () => {
+    const collection = await createGroup({
+      parent: { group: root, authority: franchise },
+    });
+
+    const signature = await setGroupFrozen(collection, true);
+    const event = (await fetchCpiEvents(signature)).find(
+      ({ name }) => name === "GroupFrozenEvent"
+    );
+    expect(event.data.group.toBase58()).to.equal(collection.toBase58());
+    expect(
+      event.data.groupPath.map((group: PublicKey) => group.toBase58())
+    ).to.deep.equal([root.toBase58()]);
+  });
+
+  it("Includes the group path in membership events on mint", async () => {
+    const collection = await createGroup({
+      parent: { group: root, authority: franchise },
+    });
+    const { ix, assetId } = await createAssetIx({ group: collection });
+
+    const signature = await send([ix]);
+    const event = (await fetchCpiEvents(signature)).find(
+      ({ name }) => name === "MembershipUpdatedEvent"
+    );
+    expect(event.data.assetId.equals(assetId)).to.be.true;
+    expect(event.data.verified).to.equal(true);
+    expect(
+      event.data.groupPath.map((group: PublicKey) => group.toBase58())
+    ).to.deep.equal([root.toBase58()]);
+  });
+});
@@ -0,0 +1,150 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  blobData,
+  burn,
+  burnIx,
+  createAsset,
+  createGroup,
+  expectError,
+  fetchAsset,
+  fetchCompressed,
+  fetchCpiEvents,
+  fundedKeypair,
+  keypair,
+  program,
+  resourceAddress,
+  send,
+  transfer,
+} from "./helpers";
+
+describe("non-transferable assets", () => {
+  let owner: Keypair;
+
+  before(async () => {
+    owner = await fundedKeypair();
+  });
+
+  it("Rejects transfers of a non-transferable asset", async () => {
+    const assetId = await createAsset({
+      recipient: owner.publicKey,
+      nonTransferable: true,
+    });
+
+    const { assetData } = await fetchAsset(assetId);
+    expect(assetData.data.nonTransferable).to.equal(true);
+    await expectError(
+      transfer(assetId, {
+        authority: owner,
+        recipient: Keypair.generate().publicKey,
+      }),
+      "AssetNonTransferable"
+    );
+  });
+
+  it("Makes every asset of a non-transferable group soulbound", async () => {
+    const group = await createGroup({ nonTransferable: true });
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+
+    const { assetData } = await fetchAsset(assetId);
+    expect(assetData.data.nonTransferable).to.equal(true);
+    await expectError(
+      transfer(assetId, {
+        authority: owner,
+        recipient: Keypair.generate().publicKey,
+      }),
+      "AssetNonTransferable"
+    );
+  });
+
+  it("Lets the asset authority recover the asset", async () => {
+    const assetId = await createAsset({
+      recipient: owner.publicKey,
+      nonTransferable: true,
+    });
+    const recovered = Keypair.generate().publicKey;
+    await transfer(assetId, { authority: keypair, recipient: recovered });
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(recovered.toBase58());
+  });
+
+  it("Cannot be recovered without an asset authority", async () => {
+    const assetId = await createAsset({
+      recipient: owner.publicKey,
+      authority: null,
+      nonTransferable: true,
+    });
+    await expectError(
+      transfer(assetId, {
+        authority: keypair,
+        recipient: Keypair.generate().publicKey,
+      }),
+      "AssetNonTransferable"
+    );
+  });
+});
+
+describe("burn", () => {
+  let owner: Keypair;
+
+  before(async () => {
+    owner = await fundedKeypair();
+  });
+
+  it("Burns a non-transferable asset with all its accounts", async () => {
+    const assetId = await createAsset({
+      recipient: owner.publicKey,
+      nonTransferable: true,
+      blob: blobData(),
+      attributes: [{ traitType: "tier", value: "gold" }],
+    });
+    const signature = await burn(assetId, { authority: owner });
+
+    const { baseData, assetData } = await fetchAsset(assetId);
+    expect(baseData).to.equal(null);
+    expect(assetData).to.equal(null);
+    for (const [resource, type] of [
+      ["blob", "Blob"],
+      ["attributes", "Attributes"],
+    ]) {
+      const address = await resourceAddress(resource, assetId);
+      expect(await fetchCompressed(type, address)).to.equal(null);
+    }
+
+    const event = (await fetchCpiEvents(signature)).find(
+      ({ name }) => name === "AssetBurnedEvent"
+    );
+    expect(event.data.assetId.toBase58()).to.equal(assetId.toBase58());
+  });
+
+  it("Requires every account of the asset", async () => {
+    const assetId = await createAsset({
+      recipient: owner.publicKey,
+      blob: blobData(),
+    });
+    const ix = await burnIx(assetId, { authority: owner, withBlob: false });
+    await expectError(send([ix], [owner]), "AssetAccountMissing");
+  });
+
+  it("Frees up the asset's place in its group", async () => {
+    const group = await createGroup({ maxSize: 1 });
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+
+    const ix = await burnIx(assetId, { authority: owner, group: null });
+    await expectError(send([ix], [owner]), "GroupAccountMissing");
+    await burn(assetId, { authority: owner });
+    const { size } = await program.account.group.fetch(group);
+    expect(size.toNumber()).to.equal(0);
+    await createAsset({ group });
+  });
+
+  it("Only lets the owner burn the asset", async () => {
+    const assetId = await createAsset({ recipient: owner.publicKey });
+    const intruder = await fundedKeypair();
+    await expectError(
+      burn(assetId, { authority: intruder }),
+      "InvalidAuthority"
+    );
+  });
+});
@@ -0,0 +1,117 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  burn,
+  createAsset,
+  createGroup,
+  expectError,
+  fetchAsset,
+  fetchCpiEvents,
+  fundedKeypair,
+  lock,
+  setGroupPermanentDelegate,
+  transfer,
+  unlock,
+} from "./helpers";
+
+async function permanentDelegateEvent(signature: string) {
+  const event = (await fetchCpiEvents(signature)).find(
+    ({ name }) => name === "PermanentDelegateEvent"
+  );
+  return event.data;
+}
+
+describe("permanent delegate", () => {
+  let group: PublicKey;
+  let permanentDelegate: Keypair;
+  let owner: Keypair;
+
+  before(async () => {
+    group = await createGroup();
+    permanentDelegate = await fundedKeypair();
+    owner = await fundedKeypair();
+    await setGroupPermanentDelegate(group, permanentDelegate.publicKey);
+  });
+
+  it("Records the group permanent delegate on minted assets", async () => {
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+
+    const { assetData } = await fetchAsset(assetId);
+    expect(assetData.data.permanentDelegate.toBase58()).to.equal(
+      permanentDelegate.publicKey.toBase58()
+    );
+  });
+
+  it("Lets the permanent delegate move the asset", async () => {
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+    const recipient = Keypair.generate().publicKey;
+    const signature = await transfer(assetId, {
+      authority: permanentDelegate,
+      recipient,
+    });
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(recipient.toBase58());
+    const event = await permanentDelegateEvent(signature);
+    expect(event.assetId.toBase58()).to.equal(assetId.toBase58());
+    expect(event.action).to.deep.equal({ transfer: {} });
+  });
+
+  it("Keeps a permanent delegate lock out of the owner's reach", async () => {
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+    const args = { authority: permanentDelegate, withAssetData: true };
+    const signature = await lock(assetId, args);
+
+    let { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.state).to.deep.equal({
+      lockedByPermanentDelegate: {},
+    });
+    expect((await permanentDelegateEvent(signature)).action).to.deep.equal({
+      lock: {},
+    });
+
+    await expectError(
+      unlock(assetId, { authority: owner }),
+      "InvalidAuthority"
+    );
+    await expectError(
+      transfer(assetId, {
+        authority: owner,
+        recipient: Keypair.generate().publicKey,
+      }),
+      "AssetIsLocked"
+    );
+
+    await unlock(assetId, args);
+    ({ baseData } = await fetchAsset(assetId));
+    expect(baseData.data.state).to.deep.equal({ unlocked: {} });
+    await expectError(unlock(assetId, args), "AssetNotLocked");
+  });
+
+  it("Lets the permanent delegate burn the asset", async () => {
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+    const signature = await burn(assetId, { authority: permanentDelegate });
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData).to.equal(null);
+    expect((await permanentDelegateEvent(signature)).action).to.deep.equal({
+      burn: {},
+    });
+  });
+
+  it("Gives other signers no clawback rights", async () => {
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+    const intruder = await fundedKeypair();
+    await expectError(
+      transfer(assetId, {
+        authority: intruder,
+        recipient: intruder.publicKey,
+      }),
+      "InvalidAuthority"
+    );
+    await expectError(
+      setGroupPermanentDelegate(group, intruder.publicKey, intruder),
+      "InvalidAuthority"
+    );
+  });
+});
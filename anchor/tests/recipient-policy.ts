@@ -0,0 +1,252 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  MerkleTree,
+  Types,
+  createAsset,
+  createGroup,
+  expectError,
+  fetchAsset,
+  fundedKeypair,
+  keypair,
+  program,
+  send,
+  setGroupFrozen,
+  setGroupPermanentDelegate,
+  transfer,
+} from "./helpers";
+
+/** Keys sorted in ascending byte order, as the policy tree requires. */
+function sortKeys(keys: PublicKey[]): PublicKey[] {
+  return [...keys].sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+}
+
+function policyTree(keys: PublicKey[]) {
+  return new MerkleTree(keys.map((key) => key.toBytes()));
+}
+
+function membershipProof(
+  keys: PublicKey[],
+  key: PublicKey
+): Types["RecipientProof"] {
+  const index = keys.findIndex((leaf) => leaf.equals(key));
+  return { membership: { "0": policyTree(keys).proof(index) } };
+}
+
+/** Proves `key` falls between the leaves at `lowIndex` and `lowIndex + 1`. */
+function nonMembershipProof(
+  keys: PublicKey[],
+  lowIndex: number,
+  highIndex = lowIndex + 1
+): Types["RecipientProof"] {
+  const tree = policyTree(keys);
+  return {
+    nonMembership: {
+      low: keys[lowIndex],
+      lowProof: tree.proof(lowIndex),
+      high: keys[highIndex],
+      highProof: tree.proof(highIndex),
+    },
+  };
+}
+
+function lowIndexOf(keys: PublicKey[], key: PublicKey) {
+  const high = keys.findIndex(
+    (leaf) => Buffer.compare(leaf.toBuffer(), key.toBuffer()) > 0
+  );
+  return high - 1;
+}
+
+function recipientPolicyAddress(group: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("recipient_policy"), group.toBuffer()],
+    program.programId
+  )[0];
+}
+
+describe("recipient policy", () => {
+  const allowed = sortKeys(
+    Array.from({ length: 3 }, () => Keypair.generate().publicKey)
+  );
+  let group: PublicKey;
+  let recipientPolicy: PublicKey;
+  let owner: Keypair;
+
+  before(async () => {
+    group = await createGroup();
+    recipientPolicy = recipientPolicyAddress(group);
+    owner = await fundedKeypair();
+  });
+
+  it("Can create an allowlist", async () => {
+    const ix = await program.methods
+      .createRecipientPolicy({ allowlist: {} }, policyTree(allowed).root)
+      .accounts({
+        payer: keypair.publicKey,
+        groupAuthority: keypair.publicKey,
+        group,
+        recipientPolicy,
+      })
+      .instruction();
+    await send([ix]);
+
+    const groupData = await program.account.group.fetch(group);
+    expect(groupData.recipientPolicy.toBase58()).to.equal(
+      recipientPolicy.toBase58()
+    );
+    const policy = await program.account.recipientPolicy.fetch(recipientPolicy);
+    expect(policy.mode).to.deep.equal({ allowlist: {} });
+  });
+
+  it("Transfers to allowed recipients", async () => {
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+    await transfer(assetId, {
+      authority: owner,
+      recipient: allowed[1],
+      recipientProof: membershipProof(allowed, allowed[1]),
+    });
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(allowed[1].toBase58());
+  });
+
+  it("Rejects recipients outside the allowlist", async () => {
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+    const recipient = Keypair.generate().publicKey;
+
+    await expectError(
+      transfer(assetId, {
+        authority: owner,
+        recipient,
+        recipientProof: membershipProof(allowed, allowed[0]),
+      }),
+      "RecipientNotAllowed"
+    );
+    await expectError(
+      transfer(assetId, { authority: owner, recipient }),
+      "InvalidRecipientProof"
+    );
+    await expectError(
+      transfer(assetId, {
+        authority: owner,
+        recipient: allowed[0],
+        recipientProof: membershipProof(allowed, allowed[0]),
+        recipientPolicy: null,
+      }),
+      "RecipientPolicyMissing"
+    );
+  });
+
+  describe("denylist", () => {
+    const denied = Keypair.generate().publicKey;
+    const leaves = sortKeys([
+      PublicKey.default,
+      denied,
+      ...Array.from({ length: 4 }, () => Keypair.generate().publicKey),
+      new PublicKey(Buffer.alloc(32, 255)),
+    ]);
+
+    before(async () => {
+      const ix = await program.methods
+        .updateRecipientPolicy({ denylist: {} }, policyTree(leaves).root)
+        .accounts({
+          groupAuthority: keypair.publicKey,
+          group,
+          recipientPolicy,
+        })
+        .instruction();
+      await send([ix]);
+    });
+
+    it("Transfers to recipients outside the denylist", async () => {
+      const assetId = await createAsset({ group, recipient: owner.publicKey });
+      const recipient = Keypair.generate().publicKey;
+      await transfer(assetId, {
+        authority: owner,
+        recipient,
+        recipientProof: nonMembershipProof(
+          leaves,
+          lowIndexOf(leaves, recipient)
+        ),
+      });
+
+      const { baseData } = await fetchAsset(assetId);
+      expect(baseData.data.owner.toBase58()).to.equal(recipient.toBase58());
+    });
+
+    it("Rejects denied recipients", async () => {
+      const assetId = await createAsset({ group, recipient: owner.publicKey });
+      const index = leaves.findIndex((leaf) => leaf.equals(denied));
+
+      // the only leaves enclosing a denied key are not adjacent
+      await expectError(
+        transfer(assetId, {
+          authority: owner,
+          recipient: denied,
+          recipientProof: nonMembershipProof(leaves, index - 1, index + 1),
+        }),
+        "RecipientNotAllowed"
+      );
+      await expectError(
+        transfer(assetId, {
+          authority: owner,
+          recipient: denied,
+          recipientProof: membershipProof(leaves, denied),
+        }),
+        "InvalidRecipientProof"
+      );
+    });
+
+    it("Lets the permanent delegate claw back past the policy", async () => {
+      const permanentDelegate = await fundedKeypair();
+      await setGroupPermanentDelegate(group, permanentDelegate.publicKey);
+      const assetId = await createAsset({ group, recipient: owner.publicKey });
+      await setGroupFrozen(group, true);
+
+      await transfer(assetId, {
+        authority: permanentDelegate,
+        recipient: denied,
+      });
+      const { baseData } = await fetchAsset(assetId);
+      expect(baseData.data.owner.toBase58()).to.equal(denied.toBase58());
+
+      await setGroupFrozen(group, false);
+      await setGroupPermanentDelegate(group, null);
+    });
+
+    it("Only lets the group authority update the list", async () => {
+      const intruder = await fundedKeypair();
+      const ix = await program.methods
+        .updateRecipientPolicy({ allowlist: {} }, policyTree(allowed).root)
+        .accounts({
+          groupAuthority: intruder.publicKey,
+          group,
+          recipientPolicy,
+        })
+        .instruction();
+      await expectError(send([ix], [intruder]), "InvalidAuthority");
+    });
+  });
+
+  describe("closing", () => {
+    it("Lifts the restriction once the policy is closed", async () => {
+      const ix = await program.methods
+        .closeRecipientPolicy()
+        .accounts({
+          receiver: keypair.publicKey,
+          groupAuthority: keypair.publicKey,
+          group,
+          recipientPolicy,
+        })
+        .instruction();
+      await send([ix]);
+
+      const assetId = await createAsset({ group, recipient: owner.publicKey });
+      const recipient = Keypair.generate().publicKey;
+      await transfer(assetId, { authority: owner, recipient });
+
+      const { baseData } = await fetchAsset(assetId);
+      expect(baseData.data.owner.toBase58()).to.equal(recipient.toBase58());
+    });
+  });
+});
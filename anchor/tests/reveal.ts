@@ -0,0 +1,323 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+} from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import { expect } from "chai";
+import {
+  MerkleTree,
+  Types,
+  accountIndex,
+  addressTree,
+  blobData,
+  connection,
+  createGroup,
+  createMintConfig,
+  expectError,
+  fetchAsset,
+  fetchCompressed,
+  fundedKeypair,
+  keypair,
+  lightAccounts,
+  mintConfigParams,
+  mintFromConfig,
+  prepare,
+  program,
+  resourceAddress,
+  send,
+  sleep,
+  toAccountMetas,
+} from "./helpers";
+
+const MAX_SUPPLY = 2;
+
+interface Metadata {
+  blob: Types["Blob"];
+  attributes: Types["Attribute"][];
+}
+
+/** Mirrors `RevealConfig::leaf`. */
+function revealLeaf(leafIndex: number, { blob, attributes }: Metadata) {
+  const length = Buffer.alloc(4);
+  length.writeUInt32LE(attributes.length);
+  return Buffer.concat([
+    new anchor.BN(leafIndex).toArrayLike(Buffer, "le", 8),
+    program.coder.types.encode("Blob", blob),
+    length,
+    ...attributes.map((attribute) =>
+      program.coder.types.encode("Attribute", attribute)
+    ),
+  ]);
+}
+
+async function updateMintConfig(
+  mintConfig: PublicKey,
+  params: Types["MintConfigParams"]
+) {
+  const { group } = await program.account.mintConfig.fetch(mintConfig);
+  const ix = await program.methods
+    .updateMintConfig(params)
+    .accounts({ groupAuthority: keypair.publicKey, group, mintConfig })
+    .instruction();
+  return send([ix]);
+}
+
+async function startReveal(mintConfig: PublicKey) {
+  const ix = await program.methods
+    .startReveal()
+    .accounts({ mintConfig, slotHashes: SYSVAR_SLOT_HASHES_PUBKEY })
+    .instruction();
+  return send([ix]);
+}
+
+async function closeMintConfig(mintConfig: PublicKey) {
+  const { group } = await program.account.mintConfig.fetch(mintConfig);
+  const ix = await program.methods
+    .closeMintConfig()
+    .accounts({
+      receiver: keypair.publicKey,
+      groupAuthority: keypair.publicKey,
+      group,
+      mintConfig,
+    })
+    .instruction();
+  return send([ix]);
+}
+
+async function reveal(
+  mintConfig: PublicKey,
+  assetId: PublicKey,
+  index: number,
+  metadata: Metadata,
+  proof: Types["MerkleProof"]
+) {
+  const { assetData } = await fetchAsset(assetId);
+  const [blob, attributes] = await Promise.all([
+    fetchCompressed<Types["Blob"]>(
+      "Blob",
+      await resourceAddress("blob", assetId)
+    ),
+    fetchCompressed<Types["Attributes"]>(
+      "Attributes",
+      await resourceAddress("attributes", assetId)
+    ),
+  ]);
+  const prepared = await prepare([assetData, blob, attributes]);
+  const addressTreeIndex = accountIndex(
+    prepared.remainingAccounts,
+    addressTree
+  );
+
+  const ix = await program.methods
+    .reveal(
+      prepared.proof,
+      Array.from(assetId.toBytes()),
+      {
+        data: assetData.data,
+        input: prepared.inputs[0],
+        addressMerkleTreeAccountIndex: addressTreeIndex,
+      },
+      {
+        index: new anchor.BN(index),
+        blob: metadata.blob,
+        attributes: metadata.attributes,
+        proof,
+        placeholderBlob: blob.data,
+        placeholderBlobInput: prepared.inputs[1],
+        placeholderAttributes: attributes.data,
+        placeholderAttributesInput: prepared.inputs[2],
+      }
+    )
+    .accounts({
+      payer: keypair.publicKey,
+      mintConfig,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+  return send([ix]);
+}
+
+describe("reveal", () => {
+  let buyer: Keypair;
+  let group: PublicKey;
+  let placeholder: Types["PlaceholderBlob"];
+  let metadata: Metadata[];
+  let tree: MerkleTree;
+  let params: Types["MintConfigParams"];
+  let mintConfig: PublicKey;
+  const assetIds: PublicKey[] = [];
+
+  before(async () => {
+    buyer = await fundedKeypair(LAMPORTS_PER_SOL);
+    group = await createGroup();
+    placeholder = {
+      creator: keypair.publicKey,
+      hash: Array.from(randomBytes(32)),
+      contentType: "image/png",
+      contentEncoding: { identity: {} },
+      txSig: Array.from(randomBytes(64)),
+    };
+    metadata = Array.from({ length: MAX_SUPPLY }, (_, i) => ({
+      blob: blobData(),
+      attributes: [{ traitType: "rank", value: String(i) }],
+    }));
+    tree = new MerkleTree(metadata.map((leaf, i) => revealLeaf(i, leaf)));
+    params = mintConfigParams({
+      maxSupply: new anchor.BN(MAX_SUPPLY),
+      reveal: { root: tree.root, placeholder },
+    });
+    mintConfig = await createMintConfig(group, params);
+  });
+
+  it("Requires a max supply to reveal", async () => {
+    await expectError(
+      createMintConfig(group, { ...params, maxSupply: null }),
+      "RevealRequiresMaxSupply"
+    );
+  });
+
+  it("Mints assets linking the placeholder", async () => {
+    await expectError(
+      mintFromConfig(mintConfig, { buyer, withPlaceholder: false }),
+      "PlaceholderMissing"
+    );
+
+    const assetId = await mintFromConfig(mintConfig, { buyer });
+    assetIds.push(assetId);
+    const blob = await fetchCompressed<Types["Blob"]>(
+      "Blob",
+      await resourceAddress("blob", assetId)
+    );
+    expect(blob.data.sharedBlobCreator.equals(placeholder.creator)).to.be.true;
+    expect(blob.data.hash).to.deep.equal(placeholder.hash);
+    const attributes = await fetchCompressed<Types["Attributes"]>(
+      "Attributes",
+      await resourceAddress("attributes", assetId)
+    );
+    expect(attributes.data.attributes).to.be.empty;
+  });
+
+  it("Locks the reveal once minting started", async () => {
+    await expectError(
+      updateMintConfig(mintConfig, { ...params, reveal: null }),
+      "RevealLocked"
+    );
+    await expectError(
+      updateMintConfig(mintConfig, {
+        ...params,
+        maxSupply: new anchor.BN(MAX_SUPPLY + 1),
+      }),
+      "RevealLocked"
+    );
+  });
+
+  it("Only reveals once the reveal started", async () => {
+    await expectError(
+      reveal(mintConfig, assetIds[0], 0, metadata[0], tree.proof(0)),
+      "RevealNotStarted"
+    );
+    await expectError(startReveal(mintConfig), "RevealLocked");
+  });
+
+  describe("after minting closes", () => {
+    let leafIndices: number[];
+
+    before(async () => {
+      assetIds.push(await mintFromConfig(mintConfig, { buyer }));
+    });
+
+    it("Draws the offset from the hash of a later slot", async () => {
+      await startReveal(mintConfig);
+      const { revealSlot, revealOffset } =
+        await program.account.mintConfig.fetch(mintConfig);
+      expect(revealOffset).to.be.null;
+      await expectError(startReveal(mintConfig), "RevealSlotNotReached");
+      await expectError(
+        mintFromConfig(mintConfig, { buyer }),
+        "RevealAlreadyStarted"
+      );
+
+      while ((await connection.getSlot()) <= revealSlot.toNumber() + 1) {
+        await sleep(400);
+      }
+      await startReveal(mintConfig);
+      const config = await program.account.mintConfig.fetch(mintConfig);
+      leafIndices = assetIds.map((_, i) =>
+        config.revealOffset.addn(i).modn(MAX_SUPPLY)
+      );
+    });
+
+    it("Starts the reveal only once", async () => {
+      await expectError(startReveal(mintConfig), "RevealAlreadyStarted");
+    });
+
+    it("Rejects metadata of other leaves", async () => {
+      const other = (leafIndices[0] + 1) % MAX_SUPPLY;
+      await expectError(
+        reveal(mintConfig, assetIds[0], 0, metadata[other], tree.proof(other)),
+        "InvalidRevealProof"
+      );
+      await expectError(
+        reveal(
+          mintConfig,
+          assetIds[0],
+          1,
+          metadata[leafIndices[1]],
+          tree.proof(leafIndices[1])
+        ),
+        "InvalidRevealProof"
+      );
+    });
+
+    it("Keeps the config open until every asset is revealed", async () => {
+      await expectError(closeMintConfig(mintConfig), "RevealIncomplete");
+    });
+
+    it("Reveals the committed metadata", async () => {
+      for (let i = 0; i < assetIds.length; i++) {
+        const leaf = metadata[leafIndices[i]];
+        await reveal(
+          mintConfig,
+          assetIds[i],
+          i,
+          leaf,
+          tree.proof(leafIndices[i])
+        );
+
+        const blob = await fetchCompressed<Types["Blob"]>(
+          "Blob",
+          await resourceAddress("blob", assetIds[i])
+        );
+        expect(blob.data.txSig).to.deep.equal(leaf.blob.txSig);
+        expect(blob.data.sharedBlobCreator).to.be.null;
+        const attributes = await fetchCompressed<Types["Attributes"]>(
+          "Attributes",
+          await resourceAddress("attributes", assetIds[i])
+        );
+        expect(attributes.data.attributes).to.deep.equal(leaf.attributes);
+      }
+
+      await expectError(
+        reveal(
+          mintConfig,
+          assetIds[0],
+          0,
+          metadata[leafIndices[0]],
+          tree.proof(leafIndices[0])
+        ),
+        "AssetNotRevealable"
+      );
+      const { revealed } = await program.account.mintConfig.fetch(mintConfig);
+      expect(revealed.toNumber()).to.equal(MAX_SUPPLY);
+    });
+
+    it("Closes the config once every asset is revealed", async () => {
+      await closeMintConfig(mintConfig);
+      expect(await connection.getAccountInfo(mintConfig)).to.equal(null);
+    });
+  });
+});
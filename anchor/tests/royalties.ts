@@ -0,0 +1,186 @@
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  Types,
+  buy,
+  buyIx,
+  connection,
+  createAsset,
+  createAssetIx,
+  createGroup,
+  creatorAccounts,
+  expectError,
+  fetchAsset,
+  fundedKeypair,
+  keypair,
+  list,
+  program,
+  send,
+  setGroupPermanentDelegate,
+  transfer,
+} from "./helpers";
+
+function royalties(enforced: boolean): Types["Royalties"] {
+  return {
+    basisPoints: 500,
+    enforced,
+    creators: [
+      { address: Keypair.generate().publicKey, share: 60 },
+      { address: Keypair.generate().publicKey, share: 40 },
+    ],
+  };
+}
+
+async function setGroupRoyalties(
+  group: PublicKey,
+  royalties: Types["Royalties"] | null
+) {
+  const ix = await program.methods
+    .setGroupRoyalties(royalties)
+    .accounts({ groupAuthority: keypair.publicKey, group })
+    .instruction();
+  return send([ix]);
+}
+
+describe("royalties", () => {
+  it("Stores asset royalties", async () => {
+    const assetRoyalties = royalties(false);
+    const assetId = await createAsset({ royalties: assetRoyalties });
+
+    const { assetData } = await fetchAsset(assetId);
+    expect(assetData.data.royalties.basisPoints).to.equal(500);
+    expect(assetData.data.royalties.creators).to.have.length(2);
+  });
+
+  it("Defaults to the group royalties", async () => {
+    const group = await createGroup();
+    const groupRoyalties = royalties(true);
+    await setGroupRoyalties(group, groupRoyalties);
+
+    const assetId = await createAsset({ group });
+    const { assetData } = await fetchAsset(assetId);
+    expect(assetData.data.royalties.enforced).to.equal(true);
+    expect(assetData.data.royalties.creators[0].address.toBase58()).to.equal(
+      groupRoyalties.creators[0].address.toBase58()
+    );
+  });
+
+  it("Rejects invalid royalties", async () => {
+    const invalid = [
+      { ...royalties(false), basisPoints: 10_001 },
+      {
+        ...royalties(false),
+        creators: [{ address: Keypair.generate().publicKey, share: 90 }],
+      },
+    ];
+    for (const assetRoyalties of invalid) {
+      const { ix } = await createAssetIx({ royalties: assetRoyalties });
+      await expectError(send([ix]), "InvalidRoyalties");
+    }
+
+    const group = await createGroup();
+    await expectError(setGroupRoyalties(group, invalid[1]), "InvalidRoyalties");
+  });
+
+  it("Freely transfers assets with unenforced royalties", async () => {
+    const assetId = await createAsset({ royalties: royalties(false) });
+    const recipient = Keypair.generate().publicKey;
+    await transfer(assetId, { recipient });
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(recipient.toBase58());
+  });
+
+  describe("enforced", () => {
+    const price = LAMPORTS_PER_SOL;
+    const assetRoyalties = royalties(true);
+    let seller: Keypair;
+    let buyer: Keypair;
+    let assetId: PublicKey;
+
+    before(async () => {
+      seller = Keypair.generate();
+      buyer = await fundedKeypair(2 * LAMPORTS_PER_SOL);
+      assetId = await createAsset({
+        recipient: seller.publicKey,
+        royalties: assetRoyalties,
+      });
+    });
+
+    it("Transfers gifts without a sale price", async () => {
+      const gift = await createAsset({ royalties: assetRoyalties });
+      const recipient = Keypair.generate().publicKey;
+      await transfer(gift, { recipient });
+
+      const { baseData } = await fetchAsset(gift);
+      expect(baseData.data.owner.toBase58()).to.equal(recipient.toBase58());
+    });
+
+    it("Pays the creators on transfers declaring a sale price", async () => {
+      const saleRoyalties = royalties(true);
+      const sold = await createAsset({ royalties: saleRoyalties });
+      const recipient = Keypair.generate().publicKey;
+      await expectError(
+        transfer(sold, { recipient, salePrice: price }),
+        "CreatorAccountMissing"
+      );
+
+      await transfer(sold, {
+        recipient,
+        salePrice: price,
+        extraAccounts: creatorAccounts(saleRoyalties),
+      });
+      const royaltiesPaid = (price * 500) / 10_000;
+      for (const { address, share } of saleRoyalties.creators) {
+        expect(await connection.getBalance(address)).to.equal(
+          (royaltiesPaid * share) / 100
+        );
+      }
+    });
+
+    it("Requires the creator accounts on sale", async () => {
+      await list(assetId, price, { authority: seller });
+
+      const ix = await buyIx(assetId, { buyer });
+      await expectError(send([ix], [buyer]), "CreatorAccountMissing");
+    });
+
+    it("Pays the creators out of the sale price", async () => {
+      await buy(assetId, {
+        buyer,
+        extraAccounts: creatorAccounts(assetRoyalties),
+      });
+
+      const royaltiesPaid = (price * 500) / 10_000;
+      for (const { address, share } of assetRoyalties.creators) {
+        expect(await connection.getBalance(address)).to.equal(
+          (royaltiesPaid * share) / 100
+        );
+      }
+      expect(await connection.getBalance(seller.publicKey)).to.equal(
+        price - royaltiesPaid
+      );
+
+      const { baseData } = await fetchAsset(assetId);
+      expect(baseData.data.owner.toBase58()).to.equal(
+        buyer.publicKey.toBase58()
+      );
+    });
+
+    it("Lets the permanent delegate move the asset without a sale", async () => {
+      const group = await createGroup();
+      const permanentDelegate = await fundedKeypair();
+      await setGroupPermanentDelegate(group, permanentDelegate.publicKey);
+      const seized = await createAsset({
+        group,
+        royalties: assetRoyalties,
+      });
+
+      const recipient = Keypair.generate().publicKey;
+      await transfer(seized, { authority: permanentDelegate, recipient });
+
+      const { baseData } = await fetchAsset(seized);
+      expect(baseData.data.owner.toBase58()).to.equal(recipient.toBase58());
+    });
+  });
+});
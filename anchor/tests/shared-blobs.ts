@@ -0,0 +1,147 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { randomBytes } from "crypto";
+import { sha256 } from "@noble/hashes/sha256";
+import {
+  Types,
+  blobData,
+  createAsset,
+  createAssetIx,
+  expectError,
+  fetchCompressed,
+  fundedKeypair,
+  keypair,
+  lightAccounts,
+  newAddress,
+  prepare,
+  program,
+  resourceAddress,
+  resourceSeed,
+  send,
+  toAccountMetas,
+} from "./helpers";
+
+function sharedBlobSeed(creator: PublicKey, hash: number[]) {
+  return resourceSeed(
+    Buffer.concat([Buffer.from("shared_blob"), creator.toBuffer()]),
+    Uint8Array.from(hash)
+  );
+}
+
+async function createSharedBlobIx(
+  sharedBlob: Types["SharedBlob"],
+  creator: Keypair
+) {
+  const address = await newAddress(
+    sharedBlobSeed(sharedBlob.creator, sharedBlob.hash)
+  );
+  const prepared = await prepare([], [address]);
+
+  const ix = await program.methods
+    .createSharedBlob(prepared.proof, sharedBlob, prepared.newAddresses[0])
+    .accounts({
+      payer: keypair.publicKey,
+      creator: creator.publicKey,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+
+  return { ix, address: address.address };
+}
+
+function sharedBlobData(creator: PublicKey): Types["SharedBlob"] {
+  return {
+    creator,
+    hash: Array.from(sha256(randomBytes(32))),
+    contentType: "image/webp",
+    contentEncoding: { identity: {} },
+    txSig: Array.from(randomBytes(64)),
+  };
+}
+
+function linkingBlob(sharedBlob: Types["SharedBlob"]): Types["Blob"] {
+  return blobData({
+    contentType: sharedBlob.contentType,
+    contentEncoding: sharedBlob.contentEncoding,
+    txSig: sharedBlob.txSig,
+    sharedBlobCreator: sharedBlob.creator,
+    hash: sharedBlob.hash,
+  });
+}
+
+describe("shared blobs", () => {
+  let creator: Keypair;
+  let sharedBlob: Types["SharedBlob"];
+  let sharedBlobAddress: PublicKey;
+
+  before(async () => {
+    creator = await fundedKeypair();
+  });
+
+  it("Can create a shared blob", async () => {
+    sharedBlob = sharedBlobData(creator.publicKey);
+    const { ix, address } = await createSharedBlobIx(sharedBlob, creator);
+    await send([ix], [creator]);
+    sharedBlobAddress = address;
+
+    const record = await fetchCompressed("SharedBlob", sharedBlobAddress);
+    expect(record.data.creator.toBase58()).to.equal(
+      creator.publicKey.toBase58()
+    );
+    expect(record.data.hash).to.deep.equal(sharedBlob.hash);
+  });
+
+  it("Can link a shared blob from several assets", async () => {
+    for (let i = 0; i < 2; i++) {
+      const assetId = await createAsset({
+        blob: linkingBlob(sharedBlob),
+        sharedBlob: await fetchCompressed("SharedBlob", sharedBlobAddress),
+      });
+
+      const blob = await fetchCompressed(
+        "Blob",
+        await resourceAddress("blob", assetId)
+      );
+      expect(blob.data.sharedBlobCreator.toBase58()).to.equal(
+        creator.publicKey.toBase58()
+      );
+      expect(blob.data.hash).to.deep.equal(sharedBlob.hash);
+    }
+  });
+
+  it("Keeps another creator's record for the same hash apart", async () => {
+    const squatter = await fundedKeypair();
+    const squatted = { ...sharedBlob, creator: squatter.publicKey };
+    const { ix, address } = await createSharedBlobIx(squatted, squatter);
+    await send([ix], [squatter]);
+
+    expect(address.toBase58()).to.not.equal(sharedBlobAddress.toBase58());
+    const record = await fetchCompressed("SharedBlob", sharedBlobAddress);
+    expect(record.data.creator.toBase58()).to.equal(
+      creator.publicKey.toBase58()
+    );
+  });
+
+  it("Rejects a shared blob created on behalf of someone else", async () => {
+    const impostor = await fundedKeypair();
+    const { ix } = await createSharedBlobIx(
+      sharedBlobData(creator.publicKey),
+      impostor
+    );
+    await expectError(send([ix], [impostor]), "InvalidAuthority");
+  });
+
+  it("Rejects a shared blob link without a hash", async () => {
+    const { ix } = await createAssetIx({
+      blob: { ...linkingBlob(sharedBlob), hash: null },
+      sharedBlob: await fetchCompressed("SharedBlob", sharedBlobAddress),
+    });
+    await expectError(send([ix]), "SharedBlobHashMissing");
+  });
+
+  it("Rejects a shared blob link without the record", async () => {
+    const { ix } = await createAssetIx({ blob: linkingBlob(sharedBlob) });
+    await expectError(send([ix]), "SharedBlobInputMissing");
+  });
+});
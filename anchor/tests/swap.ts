@@ -0,0 +1,181 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  Types,
+  accountIndex,
+  addressTree,
+  connection,
+  createAsset,
+  expectError,
+  fetchAsset,
+  fetchCpiEvents,
+  fundedKeypair,
+  keypair,
+  lightAccounts,
+  list,
+  prepare,
+  program,
+  send,
+  toAccountMetas,
+} from "./helpers";
+
+async function swapIx(
+  [assetA, ownerA]: [PublicKey, Keypair],
+  [assetB, ownerB]: [PublicKey, Keypair],
+  payment: Types["SwapPayment"] | null = null
+) {
+  const assets = await Promise.all([fetchAsset(assetA), fetchAsset(assetB)]);
+  // each leg's base data, then its asset data
+  const prepared = await prepare([
+    assets[0].baseData,
+    assets[0].assetData,
+    assets[1].baseData,
+    assets[1].assetData,
+  ]);
+  const addressTreeIndex = accountIndex(
+    prepared.remainingAccounts,
+    addressTree
+  );
+  const [legA, legB] = assets.map(
+    ({ assetId, baseData, assetData }, i): Types["SwapLeg"] => ({
+      assetId: Array.from(assetId.toBytes()),
+      baseData: baseData.data,
+      baseDataInput: prepared.inputs[2 * i],
+      assetDataParams: {
+        data: assetData.data,
+        input: prepared.inputs[2 * i + 1],
+        addressMerkleTreeAccountIndex: addressTreeIndex,
+      },
+      recipientProof: null,
+    })
+  );
+
+  return program.methods
+    .swap(prepared.proof, legA, legB, payment)
+    .accounts({
+      payer: keypair.publicKey,
+      ownerA: ownerA.publicKey,
+      ownerB: ownerB.publicKey,
+      groupA: null,
+      groupB: null,
+      recipientPolicyA: null,
+      recipientPolicyB: null,
+      paymentMint: null,
+      ownerATokenAccount: null,
+      ownerBTokenAccount: null,
+      tokenProgram: null,
+      ...lightAccounts(),
+    })
+    .remainingAccounts(toAccountMetas(prepared.remainingAccounts))
+    .instruction();
+}
+
+describe("swap", () => {
+  let ownerA: Keypair;
+  let ownerB: Keypair;
+
+  before(async () => {
+    ownerA = await fundedKeypair();
+    ownerB = await fundedKeypair();
+  });
+
+  it("Exchanges two assets between their owners", async () => {
+    const assetA = await createAsset({ recipient: ownerA.publicKey });
+    const assetB = await createAsset({ recipient: ownerB.publicKey });
+    await list(assetA, LAMPORTS_PER_SOL, { authority: ownerA });
+
+    const ix = await swapIx([assetA, ownerA], [assetB, ownerB]);
+    const signature = await send([ix], [ownerA, ownerB]);
+
+    const [a, b] = await Promise.all([fetchAsset(assetA), fetchAsset(assetB)]);
+    expect(a.baseData.data.owner.toBase58()).to.equal(
+      ownerB.publicKey.toBase58()
+    );
+    expect(b.baseData.data.owner.toBase58()).to.equal(
+      ownerA.publicKey.toBase58()
+    );
+    // the listing does not survive the change of owner
+    expect(a.baseData.data.price).to.equal(null);
+    expect(a.baseData.data.state).to.deep.equal({ unlocked: {} });
+
+    const events = (await fetchCpiEvents(signature)).filter(
+      ({ name }) => name === "OwnerUpdatedEvent"
+    );
+    expect(events.map(({ data }) => data.owner.toBase58())).to.deep.equal([
+      ownerB.publicKey.toBase58(),
+      ownerA.publicKey.toBase58(),
+    ]);
+  });
+
+  it("Pays the lamport leg to the other owner", async () => {
+    const assetA = await createAsset({ recipient: ownerA.publicKey });
+    const assetB = await createAsset({ recipient: ownerB.publicKey });
+    const amount = LAMPORTS_PER_SOL / 10;
+    const balance = await connection.getBalance(ownerB.publicKey);
+
+    const ix = await swapIx([assetA, ownerA], [assetB, ownerB], {
+      fromOwnerA: true,
+      amount: new anchor.BN(amount),
+    });
+    await send([ix], [ownerA, ownerB]);
+
+    expect(await connection.getBalance(ownerB.publicKey)).to.equal(
+      balance + amount
+    );
+  });
+
+  it("Rejects swaps with oneself", async () => {
+    const assetA = await createAsset({ recipient: ownerA.publicKey });
+    const assetB = await createAsset({ recipient: ownerA.publicKey });
+
+    const ix = await swapIx([assetA, ownerA], [assetB, ownerA]);
+    await expectError(send([ix], [ownerA]), "SwapSameOwner");
+  });
+
+  it("Rejects swapping an asset for itself", async () => {
+    const assetA = await createAsset({ recipient: ownerA.publicKey });
+
+    const ix = await swapIx([assetA, ownerA], [assetA, ownerB]);
+    await expectError(send([ix], [ownerA, ownerB]), "SwapSameAsset");
+  });
+
+  it("Requires each owner to own their leg", async () => {
+    const assetA = await createAsset({ recipient: ownerA.publicKey });
+    const assetB = await createAsset({ recipient: ownerB.publicKey });
+
+    const ix = await swapIx([assetB, ownerA], [assetA, ownerB]);
+    await expectError(send([ix], [ownerA, ownerB]), "InvalidAuthority");
+  });
+
+  it("Rejects assets that cannot be transferred freely", async () => {
+    const soulbound = await createAsset({
+      recipient: ownerA.publicKey,
+      nonTransferable: true,
+    });
+    const assetB = await createAsset({ recipient: ownerB.publicKey });
+
+    const soulboundIx = await swapIx([soulbound, ownerA], [assetB, ownerB]);
+    await expectError(
+      send([soulboundIx], [ownerA, ownerB]),
+      "AssetNonTransferable"
+    );
+  });
+
+  it("Swaps assets with enforced royalties without a sale", async () => {
+    const enforced = await createAsset({
+      recipient: ownerA.publicKey,
+      royalties: {
+        basisPoints: 500,
+        enforced: true,
+        creators: [{ address: keypair.publicKey, share: 100 }],
+      },
+    });
+    const assetB = await createAsset({ recipient: ownerB.publicKey });
+
+    const ix = await swapIx([enforced, ownerA], [assetB, ownerB]);
+    await send([ix], [ownerA, ownerB]);
+    const { baseData } = await fetchAsset(enforced);
+    expect(baseData.data.owner.equals(ownerB.publicKey)).to.be.true;
+  });
+});
@@ -0,0 +1,230 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createMint,
+  getAccount,
+  mintTo,
+} from "@solana/spl-token";
+import {
+  AccountMeta,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  MintPayment,
+  TokenPayment,
+  Types,
+  buy,
+  connection,
+  createAsset,
+  createGroup,
+  createMintConfig,
+  expectError,
+  fetchAsset,
+  fundedKeypair,
+  keypair,
+  list,
+  mintConfigParams,
+  mintFromConfig,
+} from "./helpers";
+
+const MINT_PRICE = 1_000;
+const SALE_PRICE = 10_000;
+
+async function balance(tokenAccount: PublicKey) {
+  return Number((await getAccount(connection, tokenAccount)).amount);
+}
+
+/** Creator token accounts, looked up by owner among the remaining accounts. */
+function writable(accounts: PublicKey[]): AccountMeta[] {
+  return accounts.map((pubkey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: true,
+  }));
+}
+
+describe("token payments", () => {
+  let paymentMint: PublicKey;
+  let otherMint: PublicKey;
+  let buyer: Keypair;
+  let buyerTokenAccount: PublicKey;
+
+  before(async () => {
+    paymentMint = await createMint(
+      connection,
+      keypair,
+      keypair.publicKey,
+      null,
+      6
+    );
+    otherMint = await createMint(
+      connection,
+      keypair,
+      keypair.publicKey,
+      null,
+      6
+    );
+    buyer = await fundedKeypair(LAMPORTS_PER_SOL);
+    buyerTokenAccount = await createAccount(
+      connection,
+      keypair,
+      paymentMint,
+      buyer.publicKey
+    );
+    await mintTo(
+      connection,
+      keypair,
+      paymentMint,
+      buyerTokenAccount,
+      keypair,
+      1_000_000
+    );
+  });
+
+  describe("mints", () => {
+    let mintConfig: PublicKey;
+    let payment: MintPayment;
+
+    before(async () => {
+      const treasury = Keypair.generate().publicKey;
+      mintConfig = await createMintConfig(
+        await createGroup(),
+        mintConfigParams({
+          treasury,
+          price: new anchor.BN(MINT_PRICE),
+          paymentMint,
+        })
+      );
+      payment = {
+        paymentMint,
+        buyerTokenAccount,
+        treasuryTokenAccount: await createAccount(
+          connection,
+          keypair,
+          paymentMint,
+          treasury
+        ),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      };
+    });
+
+    it("Charges the mint price in tokens", async () => {
+      const buyerBalance = await balance(buyerTokenAccount);
+
+      await mintFromConfig(mintConfig, { buyer, payment });
+      expect(await balance(payment.treasuryTokenAccount)).to.equal(MINT_PRICE);
+      expect(await balance(buyerTokenAccount)).to.equal(
+        buyerBalance - MINT_PRICE
+      );
+    });
+
+    it("Requires the configured payment accounts", async () => {
+      await expectError(
+        mintFromConfig(mintConfig, { buyer }),
+        "PaymentAccountMissing"
+      );
+      await expectError(
+        mintFromConfig(mintConfig, {
+          buyer,
+          payment: { ...payment, paymentMint: otherMint },
+        }),
+        "InvalidPaymentAccount"
+      );
+      await expectError(
+        mintFromConfig(mintConfig, {
+          buyer,
+          payment: { ...payment, treasuryTokenAccount: buyerTokenAccount },
+        }),
+        "InvalidPaymentAccount"
+      );
+    });
+  });
+
+  describe("sales", () => {
+    let royalties: Types["Royalties"];
+    let creatorTokenAccounts: PublicKey[];
+    let payment: TokenPayment;
+
+    before(async () => {
+      royalties = {
+        basisPoints: 500,
+        enforced: true,
+        creators: [
+          { address: Keypair.generate().publicKey, share: 60 },
+          { address: Keypair.generate().publicKey, share: 40 },
+        ],
+      };
+      creatorTokenAccounts = await Promise.all(
+        royalties.creators.map(({ address }) =>
+          createAccount(connection, keypair, paymentMint, address)
+        )
+      );
+      payment = {
+        paymentMint,
+        buyerTokenAccount,
+        sellerTokenAccount: await createAccount(
+          connection,
+          keypair,
+          paymentMint,
+          keypair.publicKey
+        ),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      };
+    });
+
+    it("Pays the seller and creators in tokens", async () => {
+      const assetId = await createAsset({ royalties });
+      await list(assetId, SALE_PRICE, { paymentMint });
+      const buyerBalance = await balance(buyerTokenAccount);
+
+      await buy(assetId, {
+        buyer,
+        payment,
+        extraAccounts: writable(creatorTokenAccounts),
+      });
+      const { baseData } = await fetchAsset(assetId);
+      expect(baseData.data.owner.equals(buyer.publicKey)).to.be.true;
+      expect(await balance(creatorTokenAccounts[0])).to.equal(300);
+      expect(await balance(creatorTokenAccounts[1])).to.equal(200);
+      expect(await balance(payment.sellerTokenAccount)).to.equal(9_500);
+      expect(await balance(buyerTokenAccount)).to.equal(
+        buyerBalance - SALE_PRICE
+      );
+    });
+
+    it("Requires the listing's payment accounts", async () => {
+      const assetId = await createAsset({ royalties });
+      await list(assetId, SALE_PRICE, { paymentMint });
+      const extraAccounts = writable(creatorTokenAccounts);
+
+      await expectError(
+        buy(assetId, { buyer, extraAccounts }),
+        "PaymentAccountMissing"
+      );
+      await expectError(
+        buy(assetId, {
+          buyer,
+          payment: { ...payment, paymentMint: otherMint },
+          extraAccounts,
+        }),
+        "InvalidPaymentAccount"
+      );
+      await expectError(
+        buy(assetId, {
+          buyer,
+          payment: { ...payment, sellerTokenAccount: buyerTokenAccount },
+          extraAccounts,
+        }),
+        "InvalidPaymentAccount"
+      );
+      await expectError(
+        buy(assetId, { buyer, payment }),
+        "CreatorAccountMissing"
+      );
+    });
+  });
+});
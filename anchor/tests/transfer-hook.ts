@@ -0,0 +1,107 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { defaultStaticAccountsStruct } from "@lightprotocol/stateless.js";
+import { expect } from "chai";
+import {
+  createAsset,
+  createGroup,
+  expectError,
+  fetchAsset,
+  fundedKeypair,
+  keypair,
+  program,
+  send,
+  transfer,
+} from "./helpers";
+
+async function setGroupTransferHook(
+  group: PublicKey,
+  transferHookProgram: PublicKey | null,
+  groupAuthority: Keypair = keypair
+) {
+  const ix = await program.methods
+    .setGroupTransferHook(transferHookProgram)
+    .accounts({ groupAuthority: groupAuthority.publicKey, group })
+    .instruction();
+  return send([ix], [groupAuthority]);
+}
+
+function hookAccounts(transferHookProgram: PublicKey) {
+  return [{ pubkey: transferHookProgram, isSigner: false, isWritable: false }];
+}
+
+describe("transfer hooks", () => {
+  // accepts any instruction, standing in for a hook that approves
+  const approvingHook = defaultStaticAccountsStruct().noopProgram;
+  // rejects the hook instruction, standing in for a hook that denies
+  const rejectingHook = SystemProgram.programId;
+  let group: PublicKey;
+  let owner: Keypair;
+
+  before(async () => {
+    group = await createGroup();
+    owner = await fundedKeypair();
+  });
+
+  it("Invokes the group transfer hook", async () => {
+    await setGroupTransferHook(group, approvingHook);
+    const groupData = await program.account.group.fetch(group);
+    expect(groupData.transferHookProgram.toBase58()).to.equal(
+      approvingHook.toBase58()
+    );
+
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+    const recipient = Keypair.generate().publicKey;
+    await transfer(assetId, {
+      authority: owner,
+      recipient,
+      extraAccounts: hookAccounts(approvingHook),
+    });
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(recipient.toBase58());
+  });
+
+  it("Requires the hook program account", async () => {
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+    await expectError(
+      transfer(assetId, {
+        authority: owner,
+        recipient: Keypair.generate().publicKey,
+      }),
+      "TransferHookProgramMissing"
+    );
+  });
+
+  it("Fails the transfer when the hook fails", async () => {
+    await setGroupTransferHook(group, rejectingHook);
+
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+    await expectError(
+      transfer(assetId, {
+        authority: owner,
+        recipient: Keypair.generate().publicKey,
+        extraAccounts: hookAccounts(rejectingHook),
+      }),
+      "invalid instruction data"
+    );
+  });
+
+  it("Transfers without a hook once it is cleared", async () => {
+    await setGroupTransferHook(group, null);
+
+    const assetId = await createAsset({ group, recipient: owner.publicKey });
+    const recipient = Keypair.generate().publicKey;
+    await transfer(assetId, { authority: owner, recipient });
+
+    const { baseData } = await fetchAsset(assetId);
+    expect(baseData.data.owner.toBase58()).to.equal(recipient.toBase58());
+  });
+
+  it("Only lets the group authority set the hook", async () => {
+    const intruder = await fundedKeypair();
+    await expectError(
+      setGroupTransferHook(group, approvingHook, intruder),
+      "InvalidAuthority"
+    );
+  });
+});
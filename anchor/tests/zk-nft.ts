@@ -61,7 +61,7 @@ describe("zk-nft", () => {
     group = groupKeypair.publicKey;
 
     const ix = await program.methods
-      .createGroup(new anchor.BN(0))
+      .createGroup(new anchor.BN(0), false)
       .accounts({
         payer: provider.wallet.publicKey,
         groupAuthority: provider.wallet.publicKey,
@@ -193,9 +193,14 @@ describe("zk-nft", () => {
         {
           data: {
             contentType: "application/json",
+            contentEncoding: { identity: {} },
             txSig,
+            sharedBlobCreator: null,
+            uploadedBlob: null,
+            hash: null,
           },
           proof: blobProof.compressedProof,
+          sharedBlobInput: null,
         },
         {
           data: attribute.map((attribute) => ({
@@ -203,7 +208,9 @@ describe("zk-nft", () => {
             value: attribute.value,
           })),
           proof: attributesProof.compressedProof,
-        }
+        },
+        null,
+        false
       )
       .accounts({
         payer: provider.wallet.publicKey,
@@ -211,6 +218,7 @@ describe("zk-nft", () => {
         authority: provider.wallet.publicKey,
         recipient,
         group,
+        uploadedBlob: null,
         cpiAuthorityPda: PublicKey.findProgramAddressSync(
           [Buffer.from("cpi_authority")],
           program.programId
@@ -249,11 +257,34 @@ describe("zk-nft", () => {
 
     const groupData = await program.account.group.fetch(group);
     expect(groupData.size.toNumber()).to.equal(1);
+
+    const assetDataRes = await rpcRequest(
+      connection.compressionApiEndpoint,
+      "getCompressedAccount",
+      {
+        address: assetDataAddress.toBase58(),
+      }
+    );
+    const assetData = await program.coder.types.decode(
+      "AssetData",
+      Buffer.from(assetDataRes.result.value.data.data, "base64")
+    );
+    expect(assetData.group.toBase58()).to.equal(group.toBase58());
+    expect(assetData.hasBlob).to.equal(true);
+    expect(assetData.hasAttributes).to.equal(true);
+    expect(assetData.verified).to.equal(true);
+    expect(assetData.nonTransferable).to.equal(false);
+    expect(assetData.royalties).to.equal(null);
   });
 
   it("Can transfer asset", async () => {
     const addressTree = defaultTestStateTreeAccounts().addressTree;
     const baseDataAddress = await deriveAddress(baseDataSeed, addressTree);
+    const assetDataSeed = sha256(
+      Buffer.concat([Buffer.from("asset_data"), baseDataAddress.toBuffer()])
+    );
+    const assetDataAddress = await deriveAddress(assetDataSeed, addressTree);
+
     const unsafeRes = await rpcRequest(
       connection.compressionApiEndpoint,
       "getCompressedAccount",
@@ -261,48 +292,58 @@ describe("zk-nft", () => {
         address: baseDataAddress.toBase58(),
       }
     );
+    const assetDataRes = await rpcRequest(
+      connection.compressionApiEndpoint,
+      "getCompressedAccount",
+      {
+        address: assetDataAddress.toBase58(),
+      }
+    );
     const baseDataHash = bn(
       new PublicKey(unsafeRes.result.value.hash).toBytes()
     );
-    const proof = await connection.getValidityProof([baseDataHash]);
+    const assetDataHash = bn(
+      new PublicKey(assetDataRes.result.value.hash).toBytes()
+    );
+    // base data first, then asset data, in the order the program consumes them
+    const proof = await connection.getValidityProof([
+      baseDataHash,
+      assetDataHash,
+    ]);
     const baseDataOutputCompressedAccounts =
       LightSystemProgram.createNewAddressOutputState(
         Array.from(baseDataAddress.toBytes()),
         program.programId
       );
-    const baseDataAddressParams: NewAddressParams = {
-      seed: baseDataSeed,
-      addressMerkleTreeRootIndex:
-        proof.rootIndices[proof.rootIndices.length - 1],
-      addressMerkleTreePubkey: proof.merkleTrees[proof.merkleTrees.length - 1],
-      addressQueuePubkey:
-        proof.nullifierQueues[proof.nullifierQueues.length - 1],
-    };
-    const {
-      remainingAccounts: _remainingAccounts,
-      packedInputCompressedAccounts,
-    } = packCompressedAccounts(
-      [
-        {
-          address: Array.from(baseDataAddress.toBytes()),
-          data: null,
-          owner: program.programId,
-          lamports: new anchor.BN(0),
-          hash: null,
-          leafIndex: unsafeRes.result.value.leafIndex,
-          merkleTree: new PublicKey(unsafeRes.result.value.tree),
-          nullifierQueue:
-            proof.nullifierQueues[proof.nullifierQueues.length - 1],
-        },
-      ],
-      proof.rootIndices,
-      baseDataOutputCompressedAccounts
-    );
-
-    const { newAddressParamsPacked, remainingAccounts } = packNewAddressParams(
-      [baseDataAddressParams],
-      _remainingAccounts
-    );
+    const { remainingAccounts, packedInputCompressedAccounts } =
+      packCompressedAccounts(
+        [
+          {
+            address: Array.from(baseDataAddress.toBytes()),
+            data: null,
+            owner: program.programId,
+            lamports: new anchor.BN(0),
+            hash: null,
+            leafIndex: unsafeRes.result.value.leafIndex,
+            merkleTree: new PublicKey(unsafeRes.result.value.tree),
+            nullifierQueue: proof.nullifierQueues[0],
+          },
+          {
+            address: Array.from(assetDataAddress.toBytes()),
+            data: null,
+            owner: program.programId,
+            lamports: new anchor.BN(0),
+            hash: null,
+            leafIndex: assetDataRes.result.value.leafIndex,
+            merkleTree: new PublicKey(assetDataRes.result.value.tree),
+            nullifierQueue: proof.nullifierQueues[1],
+          },
+        ],
+        proof.rootIndices,
+        baseDataOutputCompressedAccounts
+      );
+    // the asset data address is derived on the address tree
+    remainingAccounts.push(addressTree);
 
     const {
       accountCompressionAuthority,
@@ -315,6 +356,10 @@ describe("zk-nft", () => {
       "BaseData",
       Buffer.from(unsafeRes.result.value.data.data, "base64")
     );
+    const assetData = await program.coder.types.decode(
+      "AssetData",
+      Buffer.from(assetDataRes.result.value.data.data, "base64")
+    );
 
     const recipient = new anchor.web3.PublicKey(
       "A8AxAQW69i65FGfpGAHiH1FmjXe4P8mxjegt4fP9CwBw"
@@ -327,12 +372,24 @@ describe("zk-nft", () => {
         {
           merkleContext: packedInputCompressedAccounts[0].merkleContext,
           rootIndex: packedInputCompressedAccounts[0].rootIndex,
-        }
+        },
+        {
+          data: assetData,
+          input: {
+            merkleContext: packedInputCompressedAccounts[1].merkleContext,
+            rootIndex: packedInputCompressedAccounts[1].rootIndex,
+          },
+          addressMerkleTreeAccountIndex: remainingAccounts.length - 1,
+        },
+        null,
+        null
       )
       .accounts({
         payer: provider.wallet.publicKey,
         authority: provider.wallet.publicKey,
         recipient,
+        group,
+        recipientPolicy: null,
         cpiAuthorityPda: PublicKey.findProgramAddressSync(
           [Buffer.from("cpi_authority")],
           program.programId
@@ -382,6 +439,8 @@ describe("zk-nft", () => {
     console.log(updatedBaseData);
     console.log(baseDataAddress.toBase58());
     expect(updatedBaseData.owner.toBase58()).to.equal(recipient.toBase58());
+    expect(updatedBaseData.state).to.deep.equal({ unlocked: {} });
+    expect(updatedBaseData.delegate).to.equal(null);
   });
 
   it.skip("Can create blob", async () => {
@@ -6,14 +6,15 @@ import { useZkNftProgram } from "@/common/hooks/useZkNftProgram";
 import { useLightRpc } from "@/common/hooks/useLightRpc";
 import {
   LightSystemProgram,
-  NewAddressParams,
   bn,
   buildTx,
   defaultStaticAccountsStruct,
+  defaultTestStateTreeAccounts,
+  deriveAddress,
   packCompressedAccounts,
-  packNewAddressParams,
   rpcRequest,
 } from "@lightprotocol/stateless.js";
+import { sha256 } from "@noble/hashes/sha256";
 import {
   ComputeBudgetProgram,
   PublicKey,
@@ -50,6 +51,16 @@ export const TransferButton = ({ assetId }: { assetId: string }) => {
           setLoading(true);
 
           const baseDataAddress = new PublicKey(assetId);
+          const addressTree = defaultTestStateTreeAccounts().addressTree;
+          const assetDataAddress = await deriveAddress(
+            sha256(
+              Buffer.concat([
+                Buffer.from("asset_data"),
+                baseDataAddress.toBuffer(),
+              ])
+            ),
+            addressTree
+          );
           const unsafeRes = await rpcRequest(
             lightRpc.compressionApiEndpoint,
             "getCompressedAccount",
@@ -57,49 +68,58 @@ export const TransferButton = ({ assetId }: { assetId: string }) => {
               address: baseDataAddress.toBase58(),
             }
           );
+          const assetDataRes = await rpcRequest(
+            lightRpc.compressionApiEndpoint,
+            "getCompressedAccount",
+            {
+              address: assetDataAddress.toBase58(),
+            }
+          );
           const baseDataHash = bn(
             new PublicKey(unsafeRes.result.value.hash).toBytes()
           );
-          const proof = await lightRpc.getValidityProof([baseDataHash]);
+          const assetDataHash = bn(
+            new PublicKey(assetDataRes.result.value.hash).toBytes()
+          );
+          // the program consumes the base data first, then the asset data
+          const proof = await lightRpc.getValidityProof([
+            baseDataHash,
+            assetDataHash,
+          ]);
           const baseDataOutputCompressedAccounts =
             LightSystemProgram.createNewAddressOutputState(
               Array.from(baseDataAddress.toBytes()),
               zkNftProgram.programId
             );
-          const baseDataAddressParams: NewAddressParams = {
-            seed: Uint8Array.from([]), // we do not need to actually pass in the seed here
-            addressMerkleTreeRootIndex:
-              proof.rootIndices[proof.rootIndices.length - 1]!,
-            addressMerkleTreePubkey:
-              proof.merkleTrees[proof.merkleTrees.length - 1]!,
-            addressQueuePubkey:
-              proof.nullifierQueues[proof.nullifierQueues.length - 1]!,
-          };
-          const {
-            remainingAccounts: _remainingAccounts,
-            packedInputCompressedAccounts,
-          } = packCompressedAccounts(
-            [
-              {
-                address: Array.from(baseDataAddress.toBytes()),
-                data: null,
-                owner: zkNftProgram.programId,
-                lamports: new BN(0),
-                hash: [], // we do not need to actually pass in the hash here
-                leafIndex: unsafeRes.result.value.leafIndex,
-                merkleTree: new PublicKey(unsafeRes.result.value.tree),
-                nullifierQueue:
-                  proof.nullifierQueues[proof.nullifierQueues.length - 1]!,
-              },
-            ],
-            proof.rootIndices,
-            baseDataOutputCompressedAccounts
-          );
-
-          const { remainingAccounts } = packNewAddressParams(
-            [baseDataAddressParams],
-            _remainingAccounts
-          );
+          const { remainingAccounts, packedInputCompressedAccounts } =
+            packCompressedAccounts(
+              [
+                {
+                  address: Array.from(baseDataAddress.toBytes()),
+                  data: null,
+                  owner: zkNftProgram.programId,
+                  lamports: new BN(0),
+                  hash: [], // we do not need to actually pass in the hash here
+                  leafIndex: unsafeRes.result.value.leafIndex,
+                  merkleTree: new PublicKey(unsafeRes.result.value.tree),
+                  nullifierQueue: proof.nullifierQueues[0]!,
+                },
+                {
+                  address: Array.from(assetDataAddress.toBytes()),
+                  data: null,
+                  owner: zkNftProgram.programId,
+                  lamports: new BN(0),
+                  hash: [],
+                  leafIndex: assetDataRes.result.value.leafIndex,
+                  merkleTree: new PublicKey(assetDataRes.result.value.tree),
+                  nullifierQueue: proof.nullifierQueues[1]!,
+                },
+              ],
+              proof.rootIndices,
+              baseDataOutputCompressedAccounts
+            );
+          // the asset data address is derived on the address tree
+          remainingAccounts.push(addressTree);
 
           const {
             accountCompressionAuthority,
@@ -112,6 +132,14 @@ export const TransferButton = ({ assetId }: { assetId: string }) => {
             "BaseData",
             Buffer.from(unsafeRes.result.value.data.data, "base64")
           );
+          const assetData = await zkNftProgram.coder.types.decode(
+            "AssetData",
+            Buffer.from(assetDataRes.result.value.data.data, "base64")
+          );
+          const group: PublicKey | null = assetData.group;
+          const groupData = group
+            ? await zkNftProgram.account.group.fetch(group)
+            : null;
 
           const recipient = new PublicKey(recipientPublicKey);
           const ix = await zkNftProgram.methods
@@ -122,12 +150,25 @@ export const TransferButton = ({ assetId }: { assetId: string }) => {
               {
                 merkleContext: packedInputCompressedAccounts[0]!.merkleContext,
                 rootIndex: packedInputCompressedAccounts[0]!.rootIndex,
-              }
+              },
+              {
+                data: assetData,
+                input: {
+                  merkleContext:
+                    packedInputCompressedAccounts[1]!.merkleContext,
+                  rootIndex: packedInputCompressedAccounts[1]!.rootIndex,
+                },
+                addressMerkleTreeAccountIndex: remainingAccounts.length - 1,
+              },
+              null,
+              null
             )
             .accounts({
               payer: publicKey,
               authority: publicKey,
               recipient,
+              group,
+              recipientPolicy: groupData?.recipientPolicy ?? null,
               cpiAuthorityPda: PublicKey.findProgramAddressSync(
                 [Buffer.from("cpi_authority")],
                 zkNftProgram.programId